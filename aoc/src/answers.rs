@@ -0,0 +1,232 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use aoc_common::{AocError, AocResult};
+use serde::Deserialize;
+
+/// Where the recorded answers are kept, next to the day crates.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The answer for each day and part, as in `answers.toml`:
+///
+/// ```toml
+/// [day1]
+/// part1 = "65912"
+/// part2 = "195625"
+/// unconfirmed = [2]
+/// ```
+///
+/// An answer is one the site accepted unless its part is listed as
+/// `unconfirmed`, meaning it is only what the code printed when it was
+/// written down.
+#[derive(Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, DayAnswers>,
+}
+
+/// One day's answers: a section of `answers.toml`, or a file of its own
+/// holding just `part1 = ...` and `part2 = ...`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DayAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+    #[serde(default)]
+    unconfirmed: Vec<u8>,
+}
+
+impl DayAnswers {
+    /// The answers in `path`, or none if it does not exist.
+    pub fn load(path: &Path) -> AocResult<DayAnswers> {
+        Ok(load_toml(path)?.unwrap_or_default())
+    }
+
+    pub fn new(part1: &str, part2: &str) -> DayAnswers {
+        DayAnswers {
+            part1: Some(part1.to_owned()),
+            part2: Some(part2.to_owned()),
+            unconfirmed: vec![],
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> AocResult<()> {
+        fs::write(path, self.to_toml())
+            .map_err(|e| AocError::from(e).in_file(path.to_string_lossy()))
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn is_confirmed(&self, part: u8) -> bool {
+        !self.unconfirmed.contains(&part)
+    }
+
+    /// A `partN = ...` line for each known answer, then which of them are
+    /// unconfirmed.
+    fn to_toml(&self) -> String {
+        let mut lines = String::new();
+        for part in [1, 2] {
+            if let Some(answer) = self.get(part) {
+                let value = toml::Value::String(answer.to_owned());
+                lines.push_str(&format!("part{} = {}\n", part, value));
+            }
+        }
+        if !self.unconfirmed.is_empty() {
+            let parts: Vec<String> = self.unconfirmed.iter().map(u8::to_string).collect();
+            lines.push_str(&format!("unconfirmed = [{}]\n", parts.join(", ")));
+        }
+        lines
+    }
+}
+
+fn load_toml<T: serde::de::DeserializeOwned>(path: &Path) -> AocResult<Option<T>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let filename = path.to_string_lossy();
+    let text = fs::read_to_string(path).map_err(|e| AocError::from(e).in_file(filename.clone()))?;
+    toml::from_str(&text)
+        .map(Some)
+        .map_err(|e| AocError::config(e.message()).in_file(filename))
+}
+
+impl Answers {
+    /// The recorded answers, or none if the file does not exist yet.
+    pub fn load(path: &Path) -> AocResult<Answers> {
+        let answers: Answers = load_toml(path)?.unwrap_or_default();
+
+        match answers.days.keys().find(|key| day_number(key).is_none()) {
+            Some(key) => Err(AocError::config(format!(
+                "`{}` is not a day, expected something like `day1`",
+                key
+            ))
+            .in_file(path.to_string_lossy())),
+            None => Ok(answers),
+        }
+    }
+
+    /// Write the answers back out, days in numeric order.
+    pub fn save(&self, path: &Path) -> AocResult<()> {
+        let mut sections = vec![];
+        for day in self.days() {
+            let answers = &self.days[&day_key(day)];
+            sections.push(format!("[{}]\n{}", day_key(day), answers.to_toml()));
+        }
+
+        fs::write(path, sections.join("\n"))
+            .map_err(|e| AocError::from(e).in_file(path.to_string_lossy()))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.days.get(&day_key(day))?.get(part)
+    }
+
+    /// Whether the site accepted the recorded answer, if there is one.
+    pub fn is_confirmed(&self, day: u8, part: u8) -> bool {
+        self.days
+            .get(&day_key(day))
+            .is_none_or(|answers| answers.is_confirmed(part))
+    }
+
+    /// Record `answer` as the one the site accepted.
+    pub fn set(&mut self, day: u8, part: u8, answer: &str) {
+        let answers = self.days.entry(day_key(day)).or_default();
+        answers.unconfirmed.retain(|&p| p != part);
+        match part {
+            1 => answers.part1 = Some(answer.to_owned()),
+            2 => answers.part2 = Some(answer.to_owned()),
+            _ => panic!("there is no part {}", part),
+        }
+    }
+
+    /// The days that have an answer recorded, in order.
+    pub fn days(&self) -> Vec<u8> {
+        let mut days: Vec<u8> = self.days.keys().filter_map(|k| day_number(k)).collect();
+        days.sort();
+        days
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{}", day)
+}
+
+fn day_number(key: &str) -> Option<u8> {
+    key.strip_prefix("day")?.parse().ok()
+}
+
+#[test]
+fn answers_file_test() {
+    let answers = Answers::load(Path::new("../answers.toml")).unwrap();
+    assert_eq!(answers.get(7, 1), Some("1778099"));
+    assert_eq!(answers.get(7, 3), None);
+    assert_eq!(answers.days(), vec![1, 2, 3, 4, 5, 6, 7, 14]);
+    // None of them were recorded from the site.
+    for day in answers.days() {
+        assert!(!answers.is_confirmed(day, 1) && !answers.is_confirmed(day, 2));
+    }
+}
+
+#[test]
+fn save_and_load_test() {
+    let path = crate::test_server::temp_dir("answers").join("answers.toml");
+    let mut answers = Answers::load(&path).unwrap();
+    answers.set(14, 2, "93");
+    answers.set(2, 1, "15");
+    answers.save(&path).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "[day2]\npart1 = \"15\"\n\n[day14]\npart2 = \"93\"\n"
+    );
+    assert_eq!(Answers::load(&path).unwrap(), answers);
+    assert_eq!(answers.days(), vec![2, 14]);
+
+    // Recording an accepted answer confirms only its own part.
+    fs::write(
+        &path,
+        "[day2]\npart1 = \"15\"\npart2 = \"12\"\nunconfirmed = [1, 2]\n",
+    )
+    .unwrap();
+    let mut answers = Answers::load(&path).unwrap();
+    assert!(!answers.is_confirmed(2, 1));
+    answers.set(2, 1, "15");
+    assert!(answers.is_confirmed(2, 1));
+    assert!(!answers.is_confirmed(2, 2));
+    answers.save(&path).unwrap();
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "[day2]\npart1 = \"15\"\npart2 = \"12\"\nunconfirmed = [2]\n"
+    );
+}
+
+#[test]
+fn bad_day_test() {
+    let path = crate::test_server::temp_dir("answers-bad").join("answers.toml");
+    fs::write(&path, "[monday]\npart1 = \"1\"\n").unwrap();
+    assert!(Answers::load(&path).is_err());
+}
+
+#[test]
+fn day_answers_test() {
+    let dir = crate::test_server::temp_dir("day-answers");
+    let path = dir.join("alice.answers.toml");
+    assert_eq!(DayAnswers::load(&path), Ok(DayAnswers::default()));
+
+    fs::write(&path, "part1 = \"CMZ\"\n").unwrap();
+    let answers = DayAnswers::load(&path).unwrap();
+    assert_eq!(answers.get(1), Some("CMZ"));
+    assert_eq!(answers.get(2), None);
+
+    DayAnswers::new("CMZ", "M\"C").save(&path).unwrap();
+    assert_eq!(DayAnswers::load(&path).unwrap().get(2), Some("M\"C"));
+
+    fs::write(&path, "[day5]\npart1 = \"CMZ\"\n").unwrap();
+    assert!(DayAnswers::load(&path).is_err());
+}
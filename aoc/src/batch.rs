@@ -0,0 +1,258 @@
+//! `aoc run --inputs dir/`: one day over every input in a directory, such as
+//! several accounts' inputs, each checked against the answers saved beside it.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{registry::Day, AocError, AocResult, Input};
+use tracing::info;
+
+use crate::{answers::DayAnswers, verify::Status};
+
+/// `alice.txt` is checked against `alice.answers.toml`, which holds
+/// `part1 = "..."` and `part2 = "..."`.
+const SIDECAR_EXTENSION: &str = "answers.toml";
+
+pub fn sidecar_path(input: &Path) -> PathBuf {
+    input.with_extension(SIDECAR_EXTENSION)
+}
+
+/// The inputs in `dir` by name, leaving out sidecars, subdirectories and
+/// hidden files.
+pub fn list_inputs(dir: &Path) -> AocResult<Vec<PathBuf>> {
+    let in_dir = |e: std::io::Error| AocError::from(e).in_file(dir.to_string_lossy());
+    let mut inputs = vec![];
+    for entry in fs::read_dir(dir).map_err(in_dir)? {
+        let entry = entry.map_err(in_dir)?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let is_sidecar = name.ends_with(&format!(".{}", SIDECAR_EXTENSION));
+        if !name.starts_with('.') && !is_sidecar && entry.path().is_file() {
+            inputs.push(entry.path());
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// One input's answers, and how long it took to parse and solve.
+#[derive(Debug)]
+pub struct Outcome {
+    pub file: String,
+    /// For each part asked for, its answer and how that compares with the
+    /// sidecar.
+    pub parts: Vec<(Option<String>, Status)>,
+    pub elapsed: Duration,
+}
+
+impl Outcome {
+    /// A mismatch or an error; a missing sidecar is fine.
+    pub fn is_failure(&self) -> bool {
+        self.has_mismatch()
+            || self
+                .parts
+                .iter()
+                .any(|(_, status)| matches!(status, Status::Failed(_)))
+    }
+
+    fn has_mismatch(&self) -> bool {
+        self.parts
+            .iter()
+            .any(|(_, status)| matches!(status, Status::Mismatch { .. }))
+    }
+}
+
+fn run_input(day: &Day, parts: &[u8], path: &Path) -> Outcome {
+    let filename = path.to_string_lossy();
+    info!(day = day.number, input = %filename, "solving");
+
+    let start = Instant::now();
+    let solved = Input::read(&filename).and_then(|input| {
+        day.solve(&input, parts)
+            .map_err(|e| e.in_file(filename.clone()))
+    });
+    let elapsed = start.elapsed();
+
+    let file = path
+        .file_name()
+        .map_or_else(|| filename.clone(), |name| name.to_string_lossy())
+        .into_owned();
+    let checked = solved.and_then(|answers| {
+        let expected = DayAnswers::load(&sidecar_path(path))?;
+        Ok((answers, expected))
+    });
+    let parts = match checked {
+        Ok((answers, expected)) => answers
+            .into_iter()
+            .map(|answer| {
+                let status = Status::of(&answer.value, expected.get(answer.part));
+                (Some(answer.value), status)
+            })
+            .collect(),
+        Err(e) => parts
+            .iter()
+            .map(|_| (None, Status::Failed(e.clone())))
+            .collect(),
+    };
+    Outcome {
+        file,
+        parts,
+        elapsed,
+    }
+}
+
+/// Run `day` over each of `inputs`, spread across a thread per core, and
+/// return the outcomes in the same order.
+pub fn run_batch(day: &Day, parts: &[u8], inputs: &[PathBuf]) -> Vec<Outcome> {
+    let threads = thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .min(inputs.len());
+    let next = AtomicUsize::new(0);
+
+    let mut outcomes: Vec<(usize, Outcome)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = vec![];
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(path) = inputs.get(i) else {
+                            return done;
+                        };
+                        done.push((i, run_input(day, parts, path)));
+                    }
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("a solver panicked"))
+            .collect()
+    });
+    outcomes.sort_by_key(|(i, _)| *i);
+    outcomes.into_iter().map(|(_, outcome)| outcome).collect()
+}
+
+fn cell(value: &Option<String>, status: &Status) -> String {
+    let value = value.as_deref().unwrap_or_default();
+    match status {
+        Status::Match => format!("{} ok", value),
+        Status::Mismatch { expected } => format!("{} != {}", value, expected),
+        Status::Missing => value.to_owned(),
+        Status::Failed(_) => "FAILED".to_owned(),
+    }
+}
+
+/// A row per input, with mismatches flagged at the end of the row, then the
+/// errors of any that failed.
+pub fn format_table(parts: &[u8], outcomes: &[Outcome]) -> String {
+    let mut header = vec!["File".to_owned()];
+    header.extend(parts.iter().map(|part| format!("Part {}", part)));
+    header.push("Time".to_owned());
+
+    let mut rows = vec![header];
+    for outcome in outcomes {
+        let mut row = vec![outcome.file.clone()];
+        row.extend(
+            outcome
+                .parts
+                .iter()
+                .map(|(value, status)| cell(value, status)),
+        );
+        row.push(format!("{:.1?}", outcome.elapsed));
+        rows.push(row);
+    }
+
+    let widths: Vec<usize> = (0..rows[0].len())
+        .map(|column| rows.iter().map(|row| row[column].len()).max().unwrap_or(0))
+        .collect();
+    let mut out = String::new();
+    for (i, row) in rows.iter().enumerate() {
+        let last = row.len() - 1;
+        let mut line: Vec<String> = row[..last]
+            .iter()
+            .zip(&widths)
+            .map(|(text, width)| format!("{:<width$}", text, width = width))
+            .collect();
+        line.push(format!("{:>width$}", row[last], width = widths[last]));
+        if i > 0 && outcomes[i - 1].has_mismatch() {
+            line.push("MISMATCH".to_owned());
+        }
+        out.push_str(line.join("  ").trim_end());
+        out.push('\n');
+    }
+
+    let errors: Vec<String> = outcomes
+        .iter()
+        .filter_map(|outcome| match outcome.parts.first() {
+            Some((_, Status::Failed(e))) => Some(format!("{}: {}\n", outcome.file, e)),
+            _ => None,
+        })
+        .collect();
+    if !errors.is_empty() {
+        out.push('\n');
+        out.push_str(&errors.concat());
+    }
+    out
+}
+
+#[cfg(test)]
+use crate::days::find_day;
+
+#[test]
+fn list_inputs_test() {
+    let dir = crate::test_server::temp_dir("batch-list");
+    for name in ["b.txt", "a.txt", "a.answers.toml", ".hidden"] {
+        fs::write(dir.join(name), "").unwrap();
+    }
+    fs::create_dir(dir.join("sub")).unwrap();
+
+    assert_eq!(
+        list_inputs(&dir).unwrap(),
+        [dir.join("a.txt"), dir.join("b.txt")]
+    );
+    assert_eq!(sidecar_path(&dir.join("a.txt")), dir.join("a.answers.toml"));
+    assert!(list_inputs(&dir.join("missing")).is_err());
+}
+
+#[test]
+fn run_batch_test() {
+    let dir = crate::test_server::temp_dir("batch-run");
+    let example = fs::read_to_string("../day5/test1.txt").unwrap();
+    fs::write(dir.join("alice.txt"), &example).unwrap();
+    fs::write(dir.join("alice.answers.toml"), "part1 = \"CMZ\"\n").unwrap();
+    fs::write(dir.join("bob.txt"), &example).unwrap();
+    fs::write(dir.join("bob.answers.toml"), "part2 = \"XYZ\"\n").unwrap();
+    fs::write(dir.join("carol.txt"), example.replace("move 1", "move x")).unwrap();
+
+    let inputs = list_inputs(&dir).unwrap();
+    let outcomes = run_batch(find_day(5).unwrap().unwrap(), &[1, 2], &inputs);
+    let files: Vec<_> = outcomes.iter().map(|o| o.file.as_str()).collect();
+    assert_eq!(files, ["alice.txt", "bob.txt", "carol.txt"]);
+
+    assert_eq!(
+        outcomes[0].parts[0],
+        (Some("CMZ".to_owned()), Status::Match)
+    );
+    assert_eq!(
+        outcomes[0].parts[1],
+        (Some("MCD".to_owned()), Status::Missing)
+    );
+    assert!(!outcomes[0].is_failure());
+    assert!(outcomes[1].is_failure());
+    assert!(matches!(outcomes[2].parts[1], (None, Status::Failed(_))));
+
+    let table = format_table(&[1, 2], &outcomes);
+    let lines: Vec<_> = table.lines().collect();
+    assert!(lines[0].starts_with("File       Part 1  Part 2     "));
+    assert!(lines[1].starts_with("alice.txt  CMZ ok  MCD        "));
+    assert!(lines[2].starts_with("bob.txt    CMZ     MCD != XYZ "));
+    assert!(lines[2].ends_with("  MISMATCH"));
+    assert!(lines[3].starts_with("carol.txt  FAILED  FAILED     "));
+    assert!(!lines[3].ends_with("MISMATCH"));
+    assert!(lines[5].starts_with("carol.txt: "));
+}
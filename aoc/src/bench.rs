@@ -0,0 +1,264 @@
+use std::{
+    fmt::Display,
+    fs,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{registry::Day, AocError, AocResult};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part1 => write!(f, "part 1"),
+            Stage::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+/// The spread of one stage's run times, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    pub stage: Stage,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Timing {
+    fn from_samples(stage: Stage, mut samples: Vec<Duration>) -> Timing {
+        samples.sort();
+        let ns = |d: &Duration| d.as_nanos() as u64;
+        Timing {
+            stage,
+            min_ns: samples.first().map_or(0, ns),
+            median_ns: samples.get(samples.len() / 2).map_or(0, ns),
+            max_ns: samples.last().map_or(0, ns),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayTimings {
+    pub day: u8,
+    pub timings: Vec<Timing>,
+}
+
+/// What `aoc bench --json` writes and `--baseline` reads back.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub iterations: usize,
+    pub days: Vec<DayTimings>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> AocResult<Report> {
+        let filename = path.to_string_lossy();
+        let text =
+            fs::read_to_string(path).map_err(|e| AocError::from(e).in_file(filename.clone()))?;
+        serde_json::from_str(&text).map_err(|e| {
+            AocError::config(e.to_string())
+                .in_file(filename)
+                .at_line(e.line(), "")
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> AocResult<()> {
+        let json =
+            serde_json::to_string_pretty(self).map_err(|e| AocError::config(e.to_string()))?;
+        fs::write(path, json + "\n").map_err(|e| AocError::from(e).in_file(path.to_string_lossy()))
+    }
+
+    fn find(&self, day: u8, stage: Stage) -> Option<&Timing> {
+        self.days
+            .iter()
+            .find(|d| d.day == day)?
+            .timings
+            .iter()
+            .find(|t| t.stage == stage)
+    }
+}
+
+/// Parse `input` and run each part `iterations` times apiece.
+pub fn bench_day(day: &Day, input: &str, iterations: usize) -> AocResult<Vec<Timing>> {
+    let parsed = day.parse(input)?;
+
+    Ok(vec![
+        time(Stage::Parse, iterations, || {
+            day.parse(input).map(drop_opaque)
+        })?,
+        time(Stage::Part1, iterations, || parsed.part(1).map(drop_opaque))?,
+        time(Stage::Part2, iterations, || parsed.part(2).map(drop_opaque))?,
+    ])
+}
+
+/// Drop a result without letting the optimiser skip the work behind it.
+fn drop_opaque<T>(value: T) {
+    drop(black_box(value));
+}
+
+fn time(
+    stage: Stage,
+    iterations: usize,
+    mut run: impl FnMut() -> AocResult<()>,
+) -> AocResult<Timing> {
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        run()?;
+        samples.push(start.elapsed());
+    }
+    Ok(Timing::from_samples(stage, samples))
+}
+
+/// One row of the table, compared with the baseline when there is one.
+pub struct Row<'a> {
+    pub day: u8,
+    pub timing: &'a Timing,
+    /// Percentage change of the median from the baseline's.
+    pub change: Option<f64>,
+    pub regressed: bool,
+}
+
+/// Line the report up against `baseline`; a stage regressed when its median
+/// is more than `threshold` percent slower.
+pub fn compare<'a>(report: &'a Report, baseline: Option<&Report>, threshold: f64) -> Vec<Row<'a>> {
+    let mut rows = vec![];
+    for day in &report.days {
+        for timing in &day.timings {
+            let change = baseline
+                .and_then(|b| b.find(day.day, timing.stage))
+                .filter(|base| base.median_ns > 0)
+                .map(|base| {
+                    (timing.median_ns as f64 - base.median_ns as f64) / base.median_ns as f64
+                        * 100.0
+                });
+            rows.push(Row {
+                day: day.day,
+                timing,
+                change,
+                regressed: change.is_some_and(|change| change > threshold),
+            });
+        }
+    }
+    rows
+}
+
+pub fn print_table(rows: &[Row]) {
+    println!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}  {:>8}",
+        "Day", "Stage", "min", "median", "max", "change"
+    );
+    for row in rows {
+        let change = match row.change {
+            Some(change) => format!("{:+.1}%", change),
+            None => String::new(),
+        };
+        let line = format!(
+            "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}  {:>8}{}",
+            row.day,
+            row.timing.stage.to_string(),
+            format_ns(row.timing.min_ns),
+            format_ns(row.timing.median_ns),
+            format_ns(row.timing.max_ns),
+            change,
+            if row.regressed { "  REGRESSED" } else { "" }
+        );
+        println!("{}", line.trim_end());
+    }
+}
+
+fn format_ns(ns: u64) -> String {
+    format!("{:.1?}", Duration::from_nanos(ns))
+}
+
+#[cfg(test)]
+fn timing(stage: Stage, median_ns: u64) -> Timing {
+    Timing {
+        stage,
+        min_ns: median_ns,
+        median_ns,
+        max_ns: median_ns,
+    }
+}
+
+#[test]
+fn from_samples_test() {
+    let samples = [5, 1, 9, 3, 7].map(Duration::from_nanos).to_vec();
+    assert_eq!(
+        Timing::from_samples(Stage::Part1, samples),
+        Timing {
+            stage: Stage::Part1,
+            min_ns: 1,
+            median_ns: 5,
+            max_ns: 9
+        }
+    );
+}
+
+#[test]
+fn bench_day_test() {
+    let day = crate::days::find_day(4).unwrap().unwrap();
+    let input = fs::read_to_string("../day4/test1.txt").unwrap();
+    let timings = bench_day(day, &input, 3).unwrap();
+    let stages: Vec<_> = timings.iter().map(|t| t.stage).collect();
+    assert_eq!(stages, [Stage::Parse, Stage::Part1, Stage::Part2]);
+    assert!(timings
+        .iter()
+        .all(|t| t.min_ns <= t.median_ns && t.median_ns <= t.max_ns));
+
+    assert!(bench_day(day, "1-2", 3).is_err());
+}
+
+#[test]
+fn compare_test() {
+    let report = Report {
+        iterations: 1,
+        days: vec![DayTimings {
+            day: 6,
+            timings: vec![timing(Stage::Parse, 100), timing(Stage::Part1, 150)],
+        }],
+    };
+    let baseline = Report {
+        iterations: 1,
+        days: vec![DayTimings {
+            day: 6,
+            timings: vec![timing(Stage::Parse, 95), timing(Stage::Part1, 100)],
+        }],
+    };
+
+    let rows = compare(&report, Some(&baseline), 10.0);
+    assert!(!rows[0].regressed);
+    assert_eq!(rows[1].change, Some(50.0));
+    assert!(rows[1].regressed);
+
+    let rows = compare(&report, None, 10.0);
+    assert!(rows
+        .iter()
+        .all(|row| row.change.is_none() && !row.regressed));
+}
+
+#[test]
+fn report_round_trip_test() {
+    let path = crate::test_server::temp_dir("bench").join("bench.json");
+    let report = Report {
+        iterations: 3,
+        days: vec![DayTimings {
+            day: 1,
+            timings: vec![timing(Stage::Part2, 42)],
+        }],
+    };
+    report.save(&path).unwrap();
+    assert_eq!(Report::load(&path).unwrap(), report);
+}
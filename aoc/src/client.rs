@@ -0,0 +1,89 @@
+use aoc_common::{AocError, AocResult};
+use ureq::Agent;
+
+use crate::config::{Config, YEAR};
+
+const USER_AGENT: &str = "github.com/tonyferrell/aoc2022 runner";
+
+/// Talks to the puzzle site (or whatever `base_url` points at) as the
+/// account the session token belongs to.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> AocResult<Client> {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .build()
+            .into();
+
+        Ok(Client {
+            agent,
+            base_url: config.base_url.clone(),
+            session: config.session()?.to_owned(),
+        })
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}/{}", self.base_url, YEAR, path)
+    }
+
+    pub fn get_input(&self, day: u8) -> AocResult<String> {
+        self.get(&self.url(&format!("day/{}/input", day)))
+    }
+
+    /// The JSON export of a private leaderboard.
+    pub fn get_leaderboard(&self, id: u64) -> AocResult<String> {
+        self.get(&self.url(&format!("leaderboard/private/view/{}.json", id)))
+    }
+
+    /// Send an answer and return the page the site replies with.
+    pub fn post_answer(&self, day: u8, part: u8, answer: &str) -> AocResult<String> {
+        let url = self.url(&format!("day/{}/answer", day));
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)])
+            .map_err(|e| network_error(&url, e))?;
+
+        read_body(&url, response)
+    }
+
+    fn get(&self, url: &str) -> AocResult<String> {
+        let response = self
+            .agent
+            .get(url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|e| network_error(url, e))?;
+
+        read_body(url, response)
+    }
+}
+
+fn read_body(url: &str, response: ureq::http::Response<ureq::Body>) -> AocResult<String> {
+    let status = response.status();
+    let body = response
+        .into_body()
+        .read_to_string()
+        .map_err(|e| network_error(url, e))?;
+
+    if !status.is_success() {
+        let reason = body.lines().next().unwrap_or_default().trim();
+        return Err(AocError::network(format!(
+            "{} returned {}: {}",
+            url, status, reason
+        )));
+    }
+    Ok(body)
+}
+
+fn network_error(url: &str, e: ureq::Error) -> AocError {
+    AocError::network(format!("{}: {}", url, e))
+}
@@ -0,0 +1,197 @@
+use std::{env, fs, path::PathBuf};
+
+use aoc_common::{AocError, AocResult};
+use serde::Deserialize;
+
+pub const YEAR: u16 = 2022;
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The optional `config.toml`; anything missing falls back to the
+/// environment and then to the defaults.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ConfigFile {
+    session: Option<String>,
+    base_url: Option<String>,
+    cache_dir: Option<PathBuf>,
+    leaderboard: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub cache_dir: PathBuf,
+    /// The id of the team's private leaderboard.
+    pub leaderboard: Option<u64>,
+}
+
+impl Config {
+    /// Read `$AOC_CONFIG` (or `config.toml` in the user's config directory),
+    /// letting `AOC_SESSION`, `AOC_BASE_URL`, `AOC_CACHE_DIR` and
+    /// `AOC_LEADERBOARD` override it.
+    pub fn load() -> AocResult<Config> {
+        let path = env::var_os("AOC_CONFIG")
+            .map(PathBuf::from)
+            .or_else(|| dirs::config_dir().map(|d| d.join("aoc2022").join("config.toml")));
+
+        let file = match path {
+            Some(path) if path.exists() => {
+                let filename = path.to_string_lossy();
+                let text = fs::read_to_string(&path)
+                    .map_err(|e| AocError::from(e).in_file(filename.clone()))?;
+                toml::from_str(&text)
+                    .map_err(|e| AocError::config(e.message()).in_file(filename))?
+            }
+            _ => ConfigFile::default(),
+        };
+
+        Self::from_sources(file, |key| env::var(key).ok())
+    }
+
+    fn from_sources(file: ConfigFile, env: impl Fn(&str) -> Option<String>) -> AocResult<Config> {
+        let cache_dir = env("AOC_CACHE_DIR")
+            .map(PathBuf::from)
+            .or(file.cache_dir)
+            .or_else(|| dirs::cache_dir().map(|d| d.join("aoc2022")))
+            .unwrap_or_else(|| PathBuf::from(".aoc-cache"));
+        let leaderboard = match env("AOC_LEADERBOARD") {
+            Some(id) => Some(id.trim().parse().map_err(|_| {
+                AocError::config(format!("AOC_LEADERBOARD should be a number, not `{}`", id))
+            })?),
+            None => file.leaderboard,
+        };
+
+        Ok(Config {
+            session: env("AOC_SESSION")
+                .or(file.session)
+                .map(|s| s.trim().to_owned()),
+            base_url: env("AOC_BASE_URL")
+                .or(file.base_url)
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_owned())
+                .trim_end_matches('/')
+                .to_owned(),
+            cache_dir,
+            leaderboard,
+        })
+    }
+
+    pub fn session(&self) -> AocResult<&str> {
+        self.session
+            .as_deref()
+            .filter(|s| !s.is_empty())
+            .ok_or_else(|| {
+                AocError::config("no session token; set AOC_SESSION or `session` in config.toml")
+            })
+    }
+
+    pub fn leaderboard(&self) -> AocResult<u64> {
+        self.leaderboard.ok_or_else(|| {
+            AocError::config(
+                "no leaderboard id; pass --id, or set AOC_LEADERBOARD or `leaderboard` in config.toml",
+            )
+        })
+    }
+
+    /// Where a downloaded input for `day` lives. Every account has its own
+    /// inputs, so they are kept apart by a hash of the session token.
+    pub fn cached_input(&self, day: u8) -> PathBuf {
+        let mut dir = self.cache_dir.join("inputs");
+        if let Ok(session) = self.session() {
+            dir.push(format!("{:016x}", fnv1a(session.as_bytes())));
+        }
+        dir.join(format!("day{}.txt", day))
+    }
+
+    /// Where every answer we have submitted is recorded.
+    pub fn guesses_file(&self) -> PathBuf {
+        self.cache_dir.join("guesses.toml")
+    }
+}
+
+/// 64-bit FNV-1a, which unlike `DefaultHasher` stays the same from one build
+/// to the next, so the cache keeps being found.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+impl Config {
+    pub fn for_test(base_url: &str, cache_dir: PathBuf) -> Config {
+        Config {
+            session: Some("test-session".to_owned()),
+            base_url: base_url.to_owned(),
+            cache_dir,
+            leaderboard: None,
+        }
+    }
+}
+
+#[test]
+fn environment_overrides_file_test() {
+    let file: ConfigFile = toml::from_str(
+        r#"
+        session = "from-file"
+        base_url = "http://file.example/"
+        cache_dir = "/tmp/from-file"
+        leaderboard = 1234
+        "#,
+    )
+    .unwrap();
+
+    let config = Config::from_sources(file, |key| match key {
+        "AOC_SESSION" => Some("from-env\n".to_owned()),
+        _ => None,
+    })
+    .unwrap();
+    assert_eq!(config.session.as_deref(), Some("from-env"));
+    assert_eq!(config.base_url, "http://file.example");
+    assert_eq!(config.cache_dir, PathBuf::from("/tmp/from-file"));
+    assert_eq!(config.leaderboard(), Ok(1234));
+    assert_eq!(
+        config.cached_input(7),
+        PathBuf::from(format!(
+            "/tmp/from-file/inputs/{:016x}/day7.txt",
+            fnv1a(b"from-env")
+        ))
+    );
+}
+
+#[test]
+fn cached_input_per_session_test() {
+    let config = |session: Option<&str>| Config {
+        session: session.map(str::to_owned),
+        ..Config::for_test("http://localhost", PathBuf::from("/cache"))
+    };
+
+    assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+    assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    assert_eq!(
+        config(Some("abc")).cached_input(1),
+        PathBuf::from(format!("/cache/inputs/{:016x}/day1.txt", fnv1a(b"abc")))
+    );
+    assert_ne!(
+        config(Some("abc")).cached_input(1),
+        config(Some("abd")).cached_input(1)
+    );
+    assert_eq!(
+        config(None).cached_input(1),
+        PathBuf::from("/cache/inputs/day1.txt")
+    );
+}
+
+#[test]
+fn missing_session_test() {
+    let config = Config::from_sources(ConfigFile::default(), |_| None).unwrap();
+    assert_eq!(config.base_url, DEFAULT_BASE_URL);
+    assert!(config.session().is_err());
+    assert!(config.leaderboard().is_err());
+
+    let e = Config::from_sources(ConfigFile::default(), |key| {
+        (key == "AOC_LEADERBOARD").then(|| "abc".to_owned())
+    })
+    .unwrap_err();
+    assert!(e.message.contains("`abc`"), "{}", e.message);
+}
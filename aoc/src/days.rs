@@ -0,0 +1,82 @@
+use std::sync::OnceLock;
+
+use aoc_common::{
+    registry::{self, Day},
+    AocError, AocResult,
+};
+
+// Days register their `Solution` with `#[aoc]`; they only need linking in for
+// the runner to find them. `aoc new` adds the line here and the dependency in
+// `Cargo.toml`.
+use day1 as _;
+use day14 as _;
+use day2 as _;
+use day3 as _;
+use day4 as _;
+use day5 as _;
+use day6 as _;
+use day7 as _;
+
+/// Every registered day, in order.
+pub fn all_days() -> AocResult<&'static [Day]> {
+    static DAYS: OnceLock<AocResult<Vec<Day>>> = OnceLock::new();
+    DAYS.get_or_init(registry::days)
+        .as_deref()
+        .map_err(AocError::clone)
+}
+
+pub fn find_day(number: u8) -> AocResult<Option<&'static Day>> {
+    Ok(all_days()?.iter().find(|day| day.number == number))
+}
+
+#[test]
+fn every_day_is_registered_test() {
+    let numbers: Vec<u8> = all_days().unwrap().iter().map(|d| d.number).collect();
+    assert_eq!(numbers, [1, 2, 3, 4, 5, 6, 7, 14]);
+}
+
+#[test]
+fn find_day_test() {
+    assert_eq!(find_day(7).unwrap().map(|d| d.number), Some(7));
+    assert!(find_day(8).unwrap().is_none());
+}
+
+#[test]
+fn every_day_solves_its_example_test() {
+    let examples = [
+        (1, "../day1/testinput.txt", ["9", "17"]),
+        (2, "../day2/test2.txt", ["15", "12"]),
+        (3, "../day3/test.txt", ["157", "70"]),
+        (4, "../day4/test1.txt", ["2", "4"]),
+        (5, "../day5/test1.txt", ["CMZ", "MCD"]),
+        (7, "../day7/test.txt", ["95437", "24933642"]),
+        (14, "../day14/test.txt", ["24", "93"]),
+    ];
+
+    for (number, path, expected) in examples {
+        let input = std::fs::read_to_string(path).unwrap();
+        let day = find_day(number).unwrap().unwrap();
+        let answers = day.solve(&input, &[1, 2]).unwrap();
+        let values: Vec<_> = answers.iter().map(|a| a.value.as_str()).collect();
+        assert_eq!(values, expected, "day {}", number);
+
+        let answers = day.solve_reader(&mut input.as_bytes(), &[1, 2]).unwrap();
+        let values: Vec<_> = answers.iter().map(|a| a.value.as_str()).collect();
+        assert_eq!(values, expected, "day {} from a reader", number);
+    }
+}
+
+#[test]
+fn every_day_generates_inputs_it_can_solve_test() {
+    // The smallest inputs, ordinary ones and big ones.
+    for (seed, size) in [(0, 0), (1, 30), (2, 500)] {
+        for day in all_days().unwrap() {
+            let generated = day.generate(seed, size).unwrap();
+            let answers = day.solve(&generated.input, &[1, 2]).unwrap();
+            if let Some(expected) = generated.answers {
+                let values: Vec<_> = answers.into_iter().map(|a| a.value).collect();
+                assert_eq!(values, expected, "day {}, seed {}", day.number, seed);
+            }
+        }
+    }
+}
@@ -0,0 +1,219 @@
+//! Turn a saved puzzle page into example fixtures and tests, instead of
+//! pasting them in by hand.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{AocError, AocResult};
+
+/// One part's example from the puzzle page.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+}
+
+/// Pull the examples out of a saved puzzle page. Each part is an
+/// `<article class="day-desc">`; its example input is the first
+/// `<pre><code>` block (part two reuses part one's if it has none) and its
+/// answer is the last highlighted `<code><em>` in the article.
+pub fn extract_examples(page: &str) -> AocResult<Vec<Example>> {
+    let articles = sections(page, "<article class=\"day-desc\">", "</article>");
+    if articles.is_empty() {
+        return Err(AocError::parse("no puzzle description found in the page"));
+    }
+
+    let mut examples: Vec<Example> = vec![];
+    for (i, article) in articles.iter().take(2).enumerate() {
+        let part = i as u8 + 1;
+        let input = match sections(article, "<pre><code>", "</code></pre>").first() {
+            Some(block) => page_text(block),
+            None => match examples.last() {
+                Some(previous) => previous.input.clone(),
+                None => {
+                    return Err(AocError::parse(format!(
+                        "part {} has no example input",
+                        part
+                    )))
+                }
+            },
+        };
+        let answer = sections(article, "<code><em>", "</em></code>")
+            .last()
+            .map(|answer| page_text(answer).trim().to_owned())
+            .ok_or_else(|| AocError::parse(format!("part {} has no highlighted answer", part)))?;
+
+        examples.push(Example {
+            part,
+            input,
+            answer,
+        });
+    }
+    Ok(examples)
+}
+
+/// Everything between each `start` and the following `end`.
+fn sections<'a>(text: &'a str, start: &str, end: &str) -> Vec<&'a str> {
+    let mut found = vec![];
+    let mut rest = text;
+    while let Some(from) = rest.find(start) {
+        rest = &rest[from + start.len()..];
+        let Some(to) = rest.find(end) else {
+            break;
+        };
+        found.push(&rest[..to]);
+        rest = &rest[to + end.len()..];
+    }
+    found
+}
+
+/// The text of some page markup: tags dropped and entities decoded.
+fn page_text(markup: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in markup.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Write the examples into `dir` (a day's crate) as `exampleN.txt`
+/// fixtures, generate `src/examples.rs` testing them, and make sure
+/// `src/lib.rs` includes it. Returns the files written.
+pub fn write_examples(dir: &Path, day: u8, examples: &[Example]) -> AocResult<Vec<PathBuf>> {
+    let lib = dir.join("src").join("lib.rs");
+    let lib_text = read(&lib)?;
+
+    let mut written = vec![];
+    let mut cases = vec![];
+    for example in examples {
+        // A part that reuses the previous example shares its fixture.
+        let fixture = match examples.iter().find(|e| e.input == example.input) {
+            Some(first) if first.part != example.part => format!("example{}.txt", first.part),
+            _ => {
+                let fixture = format!("example{}.txt", example.part);
+                written.push(write(&dir.join(&fixture), &example.input)?);
+                fixture
+            }
+        };
+        cases.push((fixture, example));
+    }
+
+    written.push(write(
+        &dir.join("src").join("examples.rs"),
+        &generate_tests(day, &cases),
+    )?);
+
+    if !lib_text.contains("mod examples;") {
+        let mut lib_text = lib_text;
+        if !lib_text.ends_with('\n') {
+            lib_text.push('\n');
+        }
+        lib_text.push_str("\n#[cfg(test)]\nmod examples;\n");
+        written.push(write(&lib, &lib_text)?);
+    }
+
+    Ok(written)
+}
+
+fn generate_tests(day: u8, cases: &[(String, &Example)]) -> String {
+    let mut source = format!(
+        "// Generated by `aoc examples --day {}` from the saved puzzle page.\n\
+         \n\
+         use aoc_common::registry::day;\n",
+        day
+    );
+
+    for (fixture, example) in cases {
+        source.push_str(&format!(
+            "\n\
+             #[test]\n\
+             fn example_part{part}_test() {{\n    \
+                 let answers = day({day}).unwrap().solve(include_str!(\"../{fixture}\"), &[{part}]).unwrap();\n    \
+                 assert_eq!(answers[0].value, {answer:?});\n\
+             }}\n",
+            part = example.part,
+            day = day,
+            fixture = fixture,
+            answer = example.answer,
+        ));
+    }
+    source
+}
+
+fn read(path: &Path) -> AocResult<String> {
+    fs::read_to_string(path).map_err(|e| AocError::from(e).in_file(path.to_string_lossy()))
+}
+
+fn write(path: &Path, contents: &str) -> AocResult<PathBuf> {
+    fs::write(path, contents).map_err(|e| AocError::from(e).in_file(path.to_string_lossy()))?;
+    Ok(path.to_owned())
+}
+
+#[test]
+fn extract_examples_test() {
+    let page = fs::read_to_string("./test_page.html").unwrap();
+    assert_eq!(
+        extract_examples(&page),
+        Ok(vec![
+            Example {
+                part: 1,
+                input: "1\n1\n\n2\n2\n\n3\n3\n3\n\n4\n".to_owned(),
+                answer: "9".to_owned(),
+            },
+            Example {
+                part: 2,
+                input: "1\n1\n\n2\n2\n\n3\n3\n3\n\n4\n".to_owned(),
+                answer: "17".to_owned(),
+            },
+        ])
+    );
+
+    assert!(extract_examples("<html></html>").is_err());
+}
+
+#[test]
+fn page_text_test() {
+    assert_eq!(
+        page_text("<em>$ cd</em> a &amp;&amp; b &lt;c&gt;"),
+        "$ cd a && b <c>"
+    );
+}
+
+#[test]
+fn write_examples_test() {
+    let dir = crate::test_server::temp_dir("examples");
+    fs::create_dir(dir.join("src")).unwrap();
+    fs::write(dir.join("src").join("lib.rs"), "mod generate;").unwrap();
+
+    let page = fs::read_to_string("./test_page.html").unwrap();
+    let examples = extract_examples(&page).unwrap();
+    let written = write_examples(&dir, 1, &examples).unwrap();
+    assert_eq!(written.len(), 3, "{:?}", written);
+    assert!(!dir.join("example2.txt").exists());
+
+    let tests = fs::read_to_string(dir.join("src").join("examples.rs")).unwrap();
+    assert!(tests.contains("day(1).unwrap().solve(include_str!(\"../example1.txt\"), &[2])"));
+    assert!(tests.contains("assert_eq!(answers[0].value, \"17\");"));
+
+    // Running it again leaves `lib.rs` alone.
+    let written = write_examples(&dir, 1, &examples).unwrap();
+    assert_eq!(written.len(), 2);
+    assert_eq!(
+        fs::read_to_string(dir.join("src").join("lib.rs")).unwrap(),
+        "mod generate;\n\n#[cfg(test)]\nmod examples;\n"
+    );
+}
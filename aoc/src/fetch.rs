@@ -0,0 +1,65 @@
+use std::{fs, path::PathBuf};
+
+use aoc_common::{AocError, AocResult};
+
+use crate::{client::Client, config::Config};
+
+/// The cached input for `day`, downloading it first if we do not have it
+/// yet. Also says whether a download happened.
+pub fn fetch_input(config: &Config, day: u8) -> AocResult<(PathBuf, bool)> {
+    let path = config.cached_input(day);
+    if path.exists() {
+        return Ok((path, false));
+    }
+
+    let input = Client::new(config)?.get_input(day)?;
+
+    let dir = path.parent().expect("cached inputs live in a directory");
+    let filename = path.to_string_lossy().into_owned();
+    let io_error = |e| AocError::from(e).in_file(filename.clone());
+    fs::create_dir_all(dir).map_err(io_error)?;
+
+    // Write to the side first so an interrupted download never looks cached.
+    let partial = path.with_extension("part");
+    fs::write(&partial, input).map_err(io_error)?;
+    fs::rename(&partial, &path).map_err(io_error)?;
+
+    Ok((path, true))
+}
+
+#[cfg(test)]
+use crate::test_server::{temp_dir, StubServer};
+
+#[test]
+fn fetch_downloads_once_test() {
+    let server = StubServer::start(vec![(200, "1\n2\n")]);
+    let config = Config::for_test(&server.base_url, temp_dir("fetch"));
+
+    let (path, downloaded) = fetch_input(&config, 7).unwrap();
+    assert!(downloaded);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n");
+
+    let (again, downloaded) = fetch_input(&config, 7).unwrap();
+    assert!(!downloaded);
+    assert_eq!(again, path);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /2022/day/7/input HTTP/1.1"));
+    assert!(requests[0]
+        .to_lowercase()
+        .contains("cookie: session=test-session"));
+}
+
+#[test]
+fn fetch_reports_http_errors_test() {
+    let server = StubServer::start(vec![(
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+    )]);
+    let config = Config::for_test(&server.base_url, temp_dir("fetch-error"));
+
+    let e = fetch_input(&config, 1).unwrap_err();
+    assert!(e.message.contains("400"), "{}", e.message);
+    assert!(!config.cached_input(1).exists());
+}
@@ -0,0 +1,134 @@
+use std::{fs, path::Path};
+
+use aoc_common::{AocError, AocResult};
+use serde::{Deserialize, Serialize};
+
+use crate::submit::Verdict;
+
+/// Every answer the site has judged, and when it will next accept one.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guesses {
+    /// Unix time before which the site will refuse another answer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_until: Option<u64>,
+    #[serde(default, rename = "guess")]
+    pub guesses: Vec<Guess>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix time the answer was submitted.
+    pub at: u64,
+}
+
+impl Guesses {
+    /// The recorded guesses, or none if nothing has been submitted yet.
+    pub fn load(path: &Path) -> AocResult<Guesses> {
+        if !path.exists() {
+            return Ok(Guesses::default());
+        }
+
+        let filename = path.to_string_lossy();
+        let text =
+            fs::read_to_string(path).map_err(|e| AocError::from(e).in_file(filename.clone()))?;
+        toml::from_str(&text).map_err(|e| AocError::config(e.message()).in_file(filename))
+    }
+
+    pub fn save(&self, path: &Path) -> AocResult<()> {
+        let filename = path.to_string_lossy();
+        let io_error = |e| AocError::from(e).in_file(filename.clone());
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+
+        let text = toml::to_string(self).map_err(|e| AocError::config(e.to_string()))?;
+        fs::write(path, text).map_err(io_error)
+    }
+
+    pub fn record(&mut self, guess: Guess) {
+        self.guesses.push(guess);
+    }
+
+    fn for_part(&self, day: u8, part: u8) -> impl Iterator<Item = &Guess> {
+        self.guesses
+            .iter()
+            .filter(move |g| g.day == day && g.part == part)
+    }
+
+    /// The guess that solved this part, if one has.
+    pub fn solved(&self, day: u8, part: u8) -> Option<&Guess> {
+        self.for_part(day, part)
+            .find(|g| g.verdict == Verdict::Correct)
+    }
+
+    /// An earlier guess of exactly `answer` that the site turned down.
+    pub fn rejected(&self, day: u8, part: u8, answer: &str) -> Option<&Guess> {
+        self.for_part(day, part)
+            .find(|g| g.answer == answer && g.verdict != Verdict::Correct)
+    }
+
+    /// The highest answer known to be too low and the lowest known to be too
+    /// high; the right answer lies strictly between them.
+    pub fn bounds(&self, day: u8, part: u8) -> (Option<i128>, Option<i128>) {
+        let numbers = |verdict| {
+            self.for_part(day, part)
+                .filter(move |g| g.verdict == verdict)
+                .filter_map(|g| g.answer.parse::<i128>().ok())
+        };
+        (
+            numbers(Verdict::TooLow).max(),
+            numbers(Verdict::TooHigh).min(),
+        )
+    }
+}
+
+#[cfg(test)]
+fn guess(day: u8, part: u8, answer: &str, verdict: Verdict) -> Guess {
+    Guess {
+        day,
+        part,
+        answer: answer.to_owned(),
+        verdict,
+        at: 0,
+    }
+}
+
+#[test]
+fn save_and_load_test() {
+    let path = crate::test_server::temp_dir("guesses").join("guesses.toml");
+    assert_eq!(Guesses::load(&path).unwrap(), Guesses::default());
+
+    let mut guesses = Guesses {
+        wait_until: Some(60),
+        guesses: vec![],
+    };
+    guesses.record(guess(1, 2, "12", Verdict::TooLow));
+    guesses.save(&path).unwrap();
+
+    assert_eq!(Guesses::load(&path).unwrap(), guesses);
+}
+
+#[test]
+fn bounds_test() {
+    let guesses = Guesses {
+        wait_until: None,
+        guesses: vec![
+            guess(1, 1, "10", Verdict::TooLow),
+            guess(1, 1, "30", Verdict::TooHigh),
+            guess(1, 1, "14", Verdict::TooLow),
+            guess(1, 1, "25", Verdict::TooHigh),
+            guess(1, 1, "20", Verdict::WrongAnswer),
+            guess(1, 2, "50", Verdict::TooHigh),
+        ],
+    };
+
+    assert_eq!(guesses.bounds(1, 1), (Some(14), Some(25)));
+    assert_eq!(guesses.bounds(1, 2), (None, Some(50)));
+    assert!(guesses.rejected(1, 1, "20").is_some());
+    assert!(guesses.rejected(1, 2, "20").is_none());
+    assert!(guesses.solved(1, 1).is_none());
+}
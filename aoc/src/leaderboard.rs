@@ -0,0 +1,297 @@
+//! `aoc leaderboard`: the team's private leaderboard, from the site's JSON
+//! export or a saved copy of it.
+
+use std::{collections::BTreeMap, fmt::Write};
+
+use aoc_common::{AocError, AocResult};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    /// Keyed by member id.
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// Missing for members who stay anonymous.
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    #[serde(default)]
+    pub last_star_ts: u64,
+    /// Day, then part, to when the star was earned.
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: u64,
+}
+
+impl Member {
+    /// The name the site shows, anonymous members included.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    fn star_time(&self, day: u8, part: u8) -> Option<u64> {
+        Some(self.completion_day_level.get(&day)?.get(&part)?.get_star_ts)
+    }
+}
+
+/// How long one member took over one day, in seconds from when it unlocked.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DayTimes {
+    pub name: String,
+    pub part1: Option<u64>,
+    pub part2: Option<u64>,
+}
+
+impl DayTimes {
+    /// Time from the first star to the second.
+    pub fn delta(&self) -> Option<u64> {
+        Some(self.part2?.saturating_sub(self.part1?))
+    }
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> AocResult<Leaderboard> {
+        serde_json::from_str(json).map_err(|e| AocError::parse(e.to_string()).at_line(e.line(), ""))
+    }
+
+    /// Highest local score first, ties going to more stars and then to
+    /// whoever got their last star earlier.
+    pub fn standings(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by_key(|m| {
+            (
+                std::cmp::Reverse(m.local_score),
+                std::cmp::Reverse(m.stars),
+                m.last_star_ts,
+                m.id,
+            )
+        });
+        members
+    }
+
+    /// Every day anyone has a star for.
+    pub fn days(&self) -> Vec<u8> {
+        let mut days: Vec<u8> = self
+            .members
+            .values()
+            .flat_map(|m| m.completion_day_level.keys().copied())
+            .collect();
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+
+    /// Everyone with a star on `day`, both parts done first, then fastest.
+    pub fn day_times(&self, year: u16, day: u8) -> AocResult<Vec<DayTimes>> {
+        let unlocked = unlock_time(year, day)?;
+        let since = |ts: u64| ts.saturating_sub(unlocked);
+
+        let mut times: Vec<DayTimes> = self
+            .standings()
+            .into_iter()
+            .filter(|m| m.completion_day_level.contains_key(&day))
+            .map(|m| DayTimes {
+                name: m.display_name(),
+                part1: m.star_time(day, 1).map(since),
+                part2: m.star_time(day, 2).map(since),
+            })
+            .collect();
+        times.sort_by_key(|t| (t.part2.is_none(), t.part2, t.part1.is_none(), t.part1));
+        Ok(times)
+    }
+}
+
+/// When `day` of `year` unlocks: midnight US Eastern, which is 05:00 UTC.
+pub fn unlock_time(year: u16, day: u8) -> AocResult<u64> {
+    if !(1..=25).contains(&day) {
+        return Err(AocError::parse(format!("there is no day {}", day)));
+    }
+    // Days from 1970-01-01 to 1 December of `year`, with years counted from
+    // March so that the leap day falls at the end of one.
+    let y = year as u64;
+    let (era, year_of_era) = (y / 400, y % 400);
+    let day_of_year = (153 * 9 + 2) / 5;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+
+    Ok((days + day as u64 - 1) * 86400 + 5 * 3600)
+}
+
+/// `1:02:03`, with hours going past 24 rather than rolling over.
+pub fn format_duration(seconds: u64) -> String {
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// The standings, then the times for each day (or just `only_day`), with the
+/// days in `implemented` marked by a `*`.
+pub fn report(
+    board: &Leaderboard,
+    year: u16,
+    implemented: &[u8],
+    only_day: Option<u8>,
+) -> AocResult<String> {
+    let mut out = String::new();
+    let standings = board.standings();
+    let width = standings
+        .iter()
+        .map(|m| m.display_name().len())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+
+    writeln!(
+        out,
+        "Private leaderboard {} ({} members)\n",
+        board.event,
+        standings.len()
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "{:>3}  {:<width$}  {:>5}  {:>5}",
+        "#", "Name", "Stars", "Score"
+    )
+    .unwrap();
+    for (rank, member) in standings.iter().enumerate() {
+        writeln!(
+            out,
+            "{:>3}  {:<width$}  {:>5}  {:>5}",
+            rank + 1,
+            member.display_name(),
+            member.stars,
+            member.local_score
+        )
+        .unwrap();
+    }
+
+    let days = match only_day {
+        Some(day) => vec![day],
+        None => board.days(),
+    };
+    for day in days {
+        let mark = if implemented.contains(&day) { " *" } else { "" };
+        writeln!(out, "\nDay {:>2}{}", day, mark).unwrap();
+        writeln!(
+            out,
+            "     {:<width$}  {:>10}  {:>10}  {:>10}",
+            "Name", "Part 1", "Part 2", "Delta"
+        )
+        .unwrap();
+        for times in board.day_times(year, day)? {
+            let time = |t: Option<u64>| t.map_or_else(|| "-".to_owned(), format_duration);
+            writeln!(
+                out,
+                "     {:<width$}  {:>10}  {:>10}  {:>10}",
+                times.name,
+                time(times.part1),
+                time(times.part2),
+                time(times.delta())
+            )
+            .unwrap();
+        }
+    }
+
+    if !implemented.is_empty() {
+        writeln!(out, "\n* solved in this repo").unwrap();
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+fn test_board() -> Leaderboard {
+    Leaderboard::parse(&std::fs::read_to_string("test_leaderboard.json").unwrap()).unwrap()
+}
+
+#[test]
+fn unlock_time_test() {
+    assert_eq!(unlock_time(2022, 1), Ok(1669870800));
+    assert_eq!(unlock_time(2020, 25), Ok(1608872400));
+    assert!(unlock_time(2022, 26).is_err());
+}
+
+#[test]
+fn standings_test() {
+    let board = test_board();
+    let names: Vec<_> = board.standings().iter().map(|m| m.display_name()).collect();
+    assert_eq!(names, ["Ada", "Grace", "(anonymous user #303)"]);
+    assert_eq!(board.days(), [1, 2, 8]);
+}
+
+#[test]
+fn day_times_test() {
+    let board = test_board();
+    let day2 = board.day_times(2022, 2).unwrap();
+    assert_eq!(
+        day2,
+        [
+            DayTimes {
+                name: "Ada".to_owned(),
+                part1: Some(1200),
+                part2: Some(1500)
+            },
+            DayTimes {
+                name: "Grace".to_owned(),
+                part1: Some(2000),
+                part2: None
+            },
+        ]
+    );
+    assert_eq!(day2[0].delta(), Some(300));
+    assert_eq!(day2[1].delta(), None);
+
+    let day1 = board.day_times(2022, 1).unwrap();
+    assert_eq!(day1[0].name, "Ada");
+    assert_eq!(day1[1].delta(), Some(700));
+}
+
+#[test]
+fn report_test() {
+    let text = report(&test_board(), 2022, &[1, 2], None).unwrap();
+    assert!(text.contains("\n  1  Ada                        4     14\n"));
+    assert!(text.contains("\nDay  2 *\n"));
+    assert!(text.contains("\nDay  8\n"));
+    assert!(text.contains("Grace                     0:03:20     0:15:00     0:11:40"));
+    assert!(text.contains("Grace                    25:00:00           -           -"));
+
+    let text = report(&test_board(), 2022, &[], Some(1)).unwrap();
+    assert!(!text.contains("Day  2"));
+    assert!(!text.contains("solved in this repo"));
+}
+
+#[test]
+fn download_test() {
+    use crate::{client::Client, config::Config, test_server::StubServer};
+
+    let server = StubServer::start(vec![(200, include_str!("../test_leaderboard.json"))]);
+    let config = Config::for_test(
+        &server.base_url,
+        crate::test_server::temp_dir("leaderboard"),
+    );
+    let json = Client::new(&config).unwrap().get_leaderboard(101).unwrap();
+
+    assert_eq!(Leaderboard::parse(&json).unwrap().members.len(), 3);
+    assert!(server.requests()[0].starts_with("GET /2022/leaderboard/private/view/101.json "));
+}
+
+#[test]
+fn parse_error_test() {
+    let e = Leaderboard::parse("{\n\"members\": 3}").unwrap_err();
+    assert_eq!(e.line, Some(2));
+}
@@ -0,0 +1,59 @@
+//! Where the days' `tracing` events go: stderr, at a level set by `-v`, with
+//! per-module overrides from `AOC_LOG`.
+
+use std::io::{stderr, IsTerminal};
+
+use tracing_subscriber::{filter::LevelFilter, EnvFilter};
+
+/// Read as `tracing` filter directives, e.g. `AOC_LOG=day14=trace,day5=debug`.
+pub const LOG_ENV: &str = "AOC_LOG";
+
+/// The level for everything `AOC_LOG` does not mention: off, then info,
+/// debug and trace for each `-v`.
+fn level(verbose: u8) -> LevelFilter {
+    match verbose {
+        0 => LevelFilter::OFF,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    }
+}
+
+fn filter(verbose: u8, directives: &str) -> EnvFilter {
+    // A bare level in `AOC_LOG` replaces the one from `-v`.
+    let sets_level = directives
+        .split(',')
+        .map(str::trim)
+        .any(|directive| !directive.is_empty() && directive.parse::<LevelFilter>().is_ok());
+    if sets_level {
+        EnvFilter::builder().parse_lossy(directives)
+    } else {
+        EnvFilter::builder().parse_lossy(format!("{},{}", level(verbose), directives))
+    }
+}
+
+pub fn init(verbose: u8) {
+    let directives = std::env::var(LOG_ENV).unwrap_or_default();
+    tracing_subscriber::fmt()
+        .with_env_filter(filter(verbose, &directives))
+        .with_writer(stderr)
+        .with_ansi(stderr().is_terminal())
+        .without_time()
+        .init();
+}
+
+#[test]
+fn filter_test() {
+    assert_eq!(filter(0, "").max_level_hint(), Some(LevelFilter::OFF));
+    assert_eq!(filter(2, "").max_level_hint(), Some(LevelFilter::DEBUG));
+    assert_eq!(filter(9, "").max_level_hint(), Some(LevelFilter::TRACE));
+    assert_eq!(
+        filter(0, "day14=trace").max_level_hint(),
+        Some(LevelFilter::TRACE)
+    );
+    assert_eq!(
+        filter(3, "day6=off").max_level_hint(),
+        Some(LevelFilter::TRACE)
+    );
+    assert_eq!(filter(3, "info").max_level_hint(), Some(LevelFilter::INFO));
+}
@@ -0,0 +1,568 @@
+mod answers;
+mod batch;
+mod bench;
+mod client;
+mod config;
+mod days;
+mod examples;
+mod fetch;
+mod guesses;
+mod leaderboard;
+mod logging;
+mod scaffold;
+mod stats;
+mod submit;
+#[cfg(test)]
+mod test_server;
+mod verify;
+mod watch;
+
+use std::{
+    fs,
+    path::Path,
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use answers::{Answers, DayAnswers, ANSWERS_FILE};
+use aoc_common::{
+    input_path, open_input, read_input, registry::Day, Answer, AocError, AocResult, STDIN,
+};
+use batch::{format_table, list_inputs, run_batch, sidecar_path};
+use bench::{bench_day, compare, print_table, DayTimings, Report};
+use clap::{Parser, Subcommand};
+use client::Client;
+use config::{Config, YEAR};
+use day1::{read_top_n, stats::Format, Elf, Mode};
+use days::{all_days, find_day};
+use examples::{extract_examples, write_examples};
+use fetch::fetch_input;
+use leaderboard::Leaderboard;
+use scaffold::new_day;
+use stats::run_stats;
+use submit::{submit_answer, Verdict};
+use tracing::info;
+use verify::{verify_day, Status};
+use watch::Watcher;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    /// Log what the solvers are doing: -v for info, -vv for debug, -vvv for trace. `AOC_LOG`
+    /// sets levels per module, e.g. `AOC_LOG=day14=trace`.
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List the implemented days.
+    List,
+    /// Run one day, or every day when no day is given.
+    Run {
+        #[arg(long)]
+        day: Option<u8>,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// The input file, or `-` to read it from stdin.
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        /// Run the day over every file in this directory, in parallel, checking each against
+        /// `<name>.answers.toml` beside it if there is one.
+        #[arg(long, requires = "day", conflicts_with = "input")]
+        inputs: Option<PathBuf>,
+    },
+    /// Download a day's input into the cache, unless it is already there.
+    Fetch {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Solve one part of a day and send the answer to the site.
+    Submit {
+        #[arg(long)]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Write a day's examples and their tests from a saved puzzle page.
+    Examples {
+        #[arg(long)]
+        day: u8,
+        /// The puzzle page, saved from the browser.
+        page: PathBuf,
+    },
+    /// Re-run every day on its real input and compare with the recorded answers.
+    Verify {
+        #[arg(long)]
+        day: Option<u8>,
+        #[arg(long, default_value = ANSWERS_FILE)]
+        answers: PathBuf,
+    },
+    /// Time parsing and each part of every day over many runs.
+    Bench {
+        #[arg(long)]
+        day: Option<u8>,
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        iterations: u64,
+        /// Write the timings here as JSON, to use as a later baseline.
+        #[arg(long)]
+        json: Option<PathBuf>,
+        /// Compare with timings saved by an earlier `--json`.
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// How many percent slower a median may get before it counts as a regression.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+        /// Time a made-up input of this size instead of the real one, see `aoc generate`.
+        #[arg(long)]
+        generate: Option<usize>,
+        #[arg(long, default_value_t = 0, requires = "generate")]
+        seed: u64,
+    },
+    /// Make up an input for a day, with its answers when the day's generator knows them.
+    Generate {
+        #[arg(long)]
+        day: u8,
+        /// Roughly how many records (elves, rounds, moves, ...) the input has.
+        #[arg(long, default_value_t = 1000)]
+        size: usize,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Write the input here, and its answers to `<name>.answers.toml` beside it, instead
+        /// of printing the input.
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Show the team's private leaderboard: standings, then each day's times.
+    Leaderboard {
+        /// A saved copy of the leaderboard's JSON, instead of downloading it.
+        #[arg(long)]
+        file: Option<PathBuf>,
+        /// The leaderboard to download, instead of the configured one.
+        #[arg(long, conflicts_with = "file")]
+        id: Option<u64>,
+        /// Only show this day's times.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+    },
+    /// Rank day 1's elves by the calories they carry, reading the input as a stream.
+    Elves {
+        /// How many elves to show.
+        #[arg(long, default_value_t = 3)]
+        top: usize,
+        /// The input file, or `-` to read it from stdin.
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Skip entries that are not numbers, with a warning, instead of failing.
+        #[arg(long)]
+        lenient: bool,
+    },
+    /// Describe how day 1's calorie totals are spread, with a histogram, and export each
+    /// elf's row.
+    Stats {
+        /// The input file, or `-` to read it from stdin.
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Skip entries that are not numbers, with a warning, instead of failing.
+        #[arg(long)]
+        lenient: bool,
+        /// How many bars to split the histogram into.
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        bins: u64,
+        /// Write each elf's number, item count, heaviest item and total here as CSV.
+        #[arg(long)]
+        csv: Option<PathBuf>,
+        /// Write each elf's number, items and total here as JSON.
+        #[arg(long)]
+        json: Option<PathBuf>,
+    },
+    /// Re-run both parts of a day whenever its input or one of its examples changes.
+    Watch {
+        #[arg(long)]
+        day: u8,
+        /// How often to look for changes, in milliseconds.
+        #[arg(long, default_value_t = 500, value_parser = clap::value_parser!(u64).range(1..))]
+        interval: u64,
+    },
+    /// Start a new day: its crate, stub solution and example test, wired into the runner.
+    New {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    logging::init(cli.verbose);
+
+    match run_command(cli.command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_command(command: Command) -> AocResult<()> {
+    match command {
+        Command::List => {
+            for day in all_days()? {
+                println!("Day {:>2}", day.number);
+            }
+        }
+        Command::Run {
+            day,
+            part,
+            input,
+            inputs,
+        } => {
+            let days: Vec<&Day> = match day {
+                Some(number) => vec![get_day(number)?],
+                None => all_days()?.iter().collect(),
+            };
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+
+            if let Some(dir) = inputs {
+                let files = list_inputs(&dir)?;
+                if files.is_empty() {
+                    return Err(AocError::config("no inputs in the directory")
+                        .in_file(dir.to_string_lossy()));
+                }
+                let outcomes = run_batch(days[0], &parts, &files);
+                print!("{}", format_table(&parts, &outcomes));
+
+                let failures = outcomes.iter().filter(|o| o.is_failure()).count();
+                if failures > 0 {
+                    return Err(AocError::solve(format!(
+                        "{} of {} inputs failed or did not match their answers",
+                        failures,
+                        outcomes.len()
+                    )));
+                }
+                return Ok(());
+            }
+
+            let config = Config::load()?;
+            for day in days {
+                let path = locate_input(&config, day.number, input.clone());
+                run_day(day, &parts, &path)?;
+            }
+        }
+        Command::Fetch { day } => {
+            let (path, downloaded) = fetch_input(&Config::load()?, day)?;
+            let how = if downloaded { "downloaded" } else { "cached" };
+            println!("Day {:>2} input: {} ({})", day, path.display(), how);
+        }
+        Command::Submit { day, part } => {
+            let config = Config::load()?;
+            let day = get_day(day)?;
+            let path = locate_input(&config, day.number, None);
+            let answer = solve_day(day, &[part], &path)?.remove(0);
+            println!("{}", format_answer(day.number, &answer));
+
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs());
+            let verdict = submit_answer(&config, day.number, part, &answer.value, now)?;
+            println!("{}", verdict);
+
+            if verdict == Verdict::Correct {
+                let path = Path::new(ANSWERS_FILE);
+                let mut answers = Answers::load(path)?;
+                if answers.get(day.number, part) != Some(answer.value.as_str()) {
+                    answers.set(day.number, part, &answer.value);
+                    answers.save(path)?;
+                    println!("Recorded in {}", ANSWERS_FILE);
+                }
+            }
+        }
+        Command::Examples { day, page } => {
+            let filename = page.to_string_lossy();
+            let examples = extract_examples(&read_input(&filename)?)
+                .map_err(|e| e.in_file(filename.clone()))?;
+            let dir = input_path(day, None)
+                .parent()
+                .expect("a day's input lives in its crate")
+                .to_owned();
+            for example in &examples {
+                println!("Part {}: {}", example.part, example.answer);
+            }
+            for path in write_examples(&dir, day, &examples)? {
+                println!("Wrote {}", path.display());
+            }
+        }
+        Command::Verify { day, answers } => {
+            let days: Vec<&Day> = match day {
+                Some(number) => vec![get_day(number)?],
+                None => all_days()?.iter().collect(),
+            };
+            let answers = Answers::load(&answers)?;
+            let config = Config::load()?;
+
+            let (mut failures, mut missing, mut unconfirmed) = (0, 0, 0);
+            for day in days {
+                let path = locate_input(&config, day.number, None);
+                let filename = path.to_string_lossy();
+                let input = match read_input(&filename) {
+                    Ok(input) => input,
+                    Err(e) => {
+                        println!("Day {:>2}: no input, {}", day.number, e);
+                        missing += 1;
+                        continue;
+                    }
+                };
+
+                for check in verify_day(day, &input, &answers) {
+                    println!("{}", check);
+                    if check.is_failure() {
+                        failures += 1;
+                    } else if check.status == Status::Missing {
+                        missing += 1;
+                    }
+                    if check.status == Status::Match && !check.confirmed {
+                        unconfirmed += 1;
+                    }
+                }
+            }
+
+            if missing > 0 {
+                println!("{} answers or inputs are missing", missing);
+            }
+            if unconfirmed > 0 {
+                println!(
+                    "{} matching answers were never confirmed by the site",
+                    unconfirmed
+                );
+            }
+            if failures > 0 {
+                return Err(AocError::solve(format!(
+                    "{} parts do not match their recorded answers",
+                    failures
+                )));
+            }
+        }
+        Command::Bench {
+            day,
+            iterations,
+            json,
+            baseline,
+            threshold,
+            generate,
+            seed,
+        } => {
+            let days: Vec<&Day> = match day {
+                Some(number) => vec![get_day(number)?],
+                None => all_days()?.iter().collect(),
+            };
+            let baseline = baseline.as_deref().map(Report::load).transpose()?;
+            let config = Config::load()?;
+
+            let iterations = iterations as usize;
+            let mut report = Report {
+                iterations,
+                days: vec![],
+            };
+            for day in days {
+                let (filename, input) = match generate {
+                    Some(size) => (
+                        format!("day {} generated input", day.number),
+                        day.generate(seed, size)?.input,
+                    ),
+                    None => {
+                        let path = locate_input(&config, day.number, None);
+                        let filename = path.to_string_lossy().into_owned();
+                        let input = read_input(&filename)?;
+                        (filename, input)
+                    }
+                };
+                let timings =
+                    bench_day(day, &input, iterations).map_err(|e| e.in_file(filename))?;
+                report.days.push(DayTimings {
+                    day: day.number,
+                    timings,
+                });
+            }
+
+            let rows = compare(&report, baseline.as_ref(), threshold);
+            print_table(&rows);
+            if let Some(json) = json {
+                report.save(&json)?;
+            }
+
+            let regressions = rows.iter().filter(|row| row.regressed).count();
+            if regressions > 0 {
+                return Err(AocError::solve(format!(
+                    "{} stages are more than {}% slower than the baseline",
+                    regressions, threshold
+                )));
+            }
+        }
+        Command::Generate {
+            day,
+            size,
+            seed,
+            out,
+        } => {
+            let generated = get_day(day)?.generate(seed, size)?;
+            let Some(path) = out else {
+                print!("{}", generated.input);
+                return Ok(());
+            };
+
+            fs::write(&path, &generated.input)
+                .map_err(|e| AocError::from(e).in_file(path.to_string_lossy()))?;
+            println!("Wrote {}", path.display());
+            if let Some([part1, part2]) = &generated.answers {
+                let sidecar = sidecar_path(&path);
+                DayAnswers::new(part1, part2).save(&sidecar)?;
+                println!("Wrote {}", sidecar.display());
+            }
+        }
+        Command::Leaderboard { file, id, day } => {
+            let json = match file {
+                Some(file) => read_input(&file.to_string_lossy())?,
+                None => {
+                    let config = Config::load()?;
+                    let id = match id {
+                        Some(id) => id,
+                        None => config.leaderboard()?,
+                    };
+                    Client::new(&config)?.get_leaderboard(id)?
+                }
+            };
+            let board = Leaderboard::parse(&json)?;
+            let implemented: Vec<u8> = all_days()?.iter().map(|day| day.number).collect();
+            print!("{}", leaderboard::report(&board, YEAR, &implemented, day)?);
+        }
+        Command::Elves {
+            top,
+            input,
+            lenient,
+        } => {
+            let path = locate_input(&Config::load()?, 1, input);
+            let filename = path.to_string_lossy();
+            let mode = if lenient { Mode::Lenient } else { Mode::Strict };
+            let best = read_top_n(&mut open_input(&filename)?, top, mode)
+                .map_err(|e| e.in_file(filename.clone()))?;
+            print!("{}", format_ranking(&best));
+        }
+        Command::Stats {
+            input,
+            lenient,
+            bins,
+            csv,
+            json,
+        } => {
+            let path = locate_input(&Config::load()?, 1, input);
+            let mode = if lenient { Mode::Lenient } else { Mode::Strict };
+            let exports: Vec<(PathBuf, Format)> = [(csv, Format::Csv), (json, Format::Json)]
+                .into_iter()
+                .filter_map(|(path, format)| Some((path?, format)))
+                .collect();
+
+            print!("{}", run_stats(&path, mode, bins as usize, &exports)?);
+            for (path, _) in exports {
+                println!("Wrote {}", path.display());
+            }
+        }
+        Command::Watch { day, interval } => {
+            let config = Config::load()?;
+            let day = get_day(day)?;
+            let dir = input_path(day.number, None)
+                .parent()
+                .expect("a day's input lives in its crate")
+                .to_owned();
+            println!("Watching {} for changes, Ctrl-C to stop", dir.display());
+            Watcher::new(day, dir, config.cached_input(day.number))
+                .run(Duration::from_millis(interval));
+        }
+        Command::New { day } => {
+            for path in new_day(Path::new("."), day)? {
+                println!("Wrote {}", path.display());
+            }
+            println!(
+                "Paste the example into day{}/test.txt and run `cargo test -p day{}`",
+                day, day
+            );
+        }
+    }
+    Ok(())
+}
+
+fn get_day(number: u8) -> AocResult<&'static Day> {
+    find_day(number)?.ok_or_else(|| AocError::solve(format!("day {} is not implemented", number)))
+}
+
+/// The input given on the command line (`-` for stdin), else the one in the
+/// day's crate, else the one `aoc fetch` downloaded.
+fn locate_input(config: &Config, day: u8, input: Option<PathBuf>) -> PathBuf {
+    let path = input_path(day, input);
+    if path == Path::new(STDIN) || path.exists() {
+        path
+    } else {
+        config.cached_input(day)
+    }
+}
+
+fn run_day(day: &Day, parts: &[u8], path: &Path) -> AocResult<()> {
+    for answer in solve_day(day, parts, path)? {
+        println!("{}", format_answer(day.number, &answer));
+    }
+    Ok(())
+}
+
+fn solve_day(day: &Day, parts: &[u8], path: &Path) -> AocResult<Vec<Answer>> {
+    let filename = path.to_string_lossy();
+    let mut input = open_input(&filename)?;
+    info!(day = day.number, input = %filename, "solving");
+
+    day.solve_reader(&mut input, parts)
+        .map_err(|e| e.in_file(filename))
+}
+
+fn format_answer(day: u8, answer: &Answer) -> String {
+    format!("Day {:>2}, part {}: {}", day, answer.part, answer.value)
+}
+
+/// One elf to a line, best first, numbered from 1 by rank.
+fn format_ranking(elves: &[Elf]) -> String {
+    elves
+        .iter()
+        .enumerate()
+        .map(|(rank, elf)| format!("{:>3}. elf {:>4}: {}\n", rank + 1, elf.number, elf.calories))
+        .collect()
+}
+
+#[test]
+fn run_day_reports_the_file_test() {
+    let e = run_day(get_day(4).unwrap(), &[1], Path::new("Cargo.toml")).unwrap_err();
+    assert_eq!(e.file.as_deref(), Some("Cargo.toml"));
+    assert_eq!(e.line, Some(1));
+}
+
+#[test]
+fn format_answer_test() {
+    let answer = Answer {
+        part: 2,
+        value: "42".to_owned(),
+    };
+    assert_eq!(format_answer(7, &answer), "Day  7, part 2: 42");
+    assert_eq!(format_answer(14, &answer), "Day 14, part 2: 42");
+}
+
+#[test]
+fn format_ranking_test() {
+    let input = fs::read_to_string("../day1/testinput.txt").unwrap();
+    let best = read_top_n(&mut input.as_bytes(), 2, Mode::Strict).unwrap();
+    assert_eq!(
+        format_ranking(&best),
+        "  1. elf    2: 9\n  2. elf    1: 4\n"
+    );
+}
@@ -0,0 +1,192 @@
+//! `aoc new`: a fresh day crate, already wired into the workspace and the
+//! runner.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{AocError, AocResult};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.template");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.template");
+
+/// Create `day{day}` under the workspace `root`, add it to the workspace
+/// members, the runner's dependencies and the day registry. Returns the
+/// files written.
+pub fn new_day(root: &Path, day: u8) -> AocResult<Vec<PathBuf>> {
+    let dir = root.join(format!("day{}", day));
+    if dir.exists() {
+        return Err(AocError::config(format!(
+            "{} already exists",
+            dir.display()
+        )));
+    }
+
+    // Work out every edit before touching anything, so a failure leaves the
+    // tree as it was.
+    let edits = [
+        (
+            root.join("Cargo.toml"),
+            "\"day",
+            format!("    \"day{}\",", day),
+            Order::Number,
+        ),
+        (
+            root.join("aoc").join("Cargo.toml"),
+            "day",
+            format!("day{0} = {{ path = \"../day{0}\" }}", day),
+            Order::Number,
+        ),
+        (
+            root.join("aoc").join("src").join("days.rs"),
+            "use day",
+            format!("use day{} as _;", day),
+            Order::Text,
+        ),
+    ]
+    .into_iter()
+    .map(|(path, prefix, line, order)| {
+        let text = read(&path)?;
+        let edited = insert_day_line(&text, day, prefix, &line, order)
+            .map_err(|e| e.in_file(path.to_string_lossy()))?;
+        Ok((path, edited))
+    })
+    .collect::<AocResult<Vec<_>>>()?;
+
+    let fill = |template: &str| template.replace("{day}", &day.to_string());
+    fs::create_dir_all(dir.join("src"))
+        .map_err(|e| AocError::from(e).in_file(dir.to_string_lossy()))?;
+    let mut written = vec![
+        write(&dir.join("Cargo.toml"), &fill(CARGO_TEMPLATE))?,
+        write(&dir.join("src").join("lib.rs"), &fill(LIB_TEMPLATE))?,
+        write(&dir.join("test.txt"), "")?,
+    ];
+    for (path, text) in edits {
+        written.push(write(&path, &text)?);
+    }
+    Ok(written)
+}
+
+/// How a run of day lines is sorted.
+#[derive(Clone, Copy)]
+enum Order {
+    Number,
+    /// As rustfmt sorts `use` lines, where `day14` comes before `day2`.
+    Text,
+}
+
+impl Order {
+    fn is_after(self, existing: u8, day: u8) -> bool {
+        match self {
+            Order::Number => existing > day,
+            Order::Text => existing.to_string() > day.to_string(),
+        }
+    }
+}
+
+/// Add `line` to the run of lines that start with `prefix` followed by a day
+/// number, keeping that run in `order`.
+fn insert_day_line(
+    text: &str,
+    day: u8,
+    prefix: &str,
+    line: &str,
+    order: Order,
+) -> AocResult<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, day_number(line, prefix)?)))
+        .collect();
+
+    if days.iter().any(|&(_, existing)| existing == day) {
+        return Err(AocError::config(format!("day {} is already listed", day)));
+    }
+    let at = match days
+        .iter()
+        .find(|&&(_, existing)| order.is_after(existing, day))
+    {
+        Some(&(i, _)) => i,
+        None => match days.last() {
+            Some(&(i, _)) => i + 1,
+            None => {
+                return Err(AocError::config(format!(
+                    "could not find where the days are listed (lines starting `{}`)",
+                    prefix
+                )))
+            }
+        },
+    };
+
+    lines.insert(at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// The day in a line like `    "day7",` (with `prefix` `"day`).
+fn day_number(line: &str, prefix: &str) -> Option<u8> {
+    let rest = line.trim_start().strip_prefix(prefix)?;
+    let digits = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    rest[..digits].parse().ok()
+}
+
+fn read(path: &Path) -> AocResult<String> {
+    fs::read_to_string(path).map_err(|e| AocError::from(e).in_file(path.to_string_lossy()))
+}
+
+fn write(path: &Path, contents: &str) -> AocResult<PathBuf> {
+    fs::write(path, contents).map_err(|e| AocError::from(e).in_file(path.to_string_lossy()))?;
+    Ok(path.to_owned())
+}
+
+#[test]
+fn insert_day_line_test() {
+    let members = "members = [\n    \"aoc\",\n    \"day2\",\n    \"day14\",\n]\n";
+    assert_eq!(
+        insert_day_line(members, 8, "\"day", "    \"day8\",", Order::Number).unwrap(),
+        "members = [\n    \"aoc\",\n    \"day2\",\n    \"day8\",\n    \"day14\",\n]\n"
+    );
+    assert_eq!(
+        insert_day_line(members, 20, "\"day", "    \"day20\",", Order::Number).unwrap(),
+        "members = [\n    \"aoc\",\n    \"day2\",\n    \"day14\",\n    \"day20\",\n]\n"
+    );
+
+    let uses = "use day1 as _;\nuse day14 as _;\nuse day2 as _;\n";
+    assert_eq!(
+        insert_day_line(uses, 15, "use day", "use day15 as _;", Order::Text).unwrap(),
+        "use day1 as _;\nuse day14 as _;\nuse day15 as _;\nuse day2 as _;\n"
+    );
+
+    assert!(insert_day_line(members, 2, "\"day", "    \"day2\",", Order::Number).is_err());
+    assert!(insert_day_line("[workspace]\n", 2, "\"day", "    \"day2\",", Order::Number).is_err());
+}
+
+#[test]
+fn new_day_test() {
+    let root = crate::test_server::temp_dir("scaffold");
+    fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+    for file in ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/days.rs"] {
+        fs::copy(Path::new("..").join(file), root.join(file)).unwrap();
+    }
+
+    let written = new_day(&root, 8).unwrap();
+    assert_eq!(written.len(), 6);
+
+    let lib = fs::read_to_string(root.join("day8/src/lib.rs")).unwrap();
+    assert!(lib.contains("#[aoc(day = 8)]\nimpl Solution for Day8 {"));
+    assert!(!lib.contains("{day}"));
+    assert!(fs::read_to_string(root.join("Cargo.toml"))
+        .unwrap()
+        .contains("    \"day7\",\n    \"day8\",\n    \"day14\",\n"));
+    assert!(fs::read_to_string(root.join("aoc/Cargo.toml"))
+        .unwrap()
+        .contains("day8 = { path = \"../day8\" }\nday14"));
+    assert!(fs::read_to_string(root.join("aoc/src/days.rs"))
+        .unwrap()
+        .contains("use day7 as _;\nuse day8 as _;\n"));
+
+    assert!(new_day(&root, 8).is_err());
+}
@@ -0,0 +1,121 @@
+//! `aoc stats`: day 1's calorie report, with each elf exported as it is read.
+
+use std::{
+    fs::{self, File},
+    io::BufWriter,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{open_input, AocError, AocResult};
+use day1::{
+    for_each_elf,
+    stats::{Format, Report, RowWriter, Summary},
+    Mode,
+};
+
+/// Where an export is written until the whole input has been read.
+fn partial_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".partial");
+    PathBuf::from(name)
+}
+
+/// The report over the elves in `input`, writing their rows to each of
+/// `exports`. An export only replaces what was there once every elf is in
+/// it; on any error the files are left as they were.
+pub fn run_stats(
+    input: &Path,
+    mode: Mode,
+    bins: usize,
+    exports: &[(PathBuf, Format)],
+) -> AocResult<Report> {
+    let filename = input.to_string_lossy();
+    let mut reader = open_input(&filename)?;
+
+    let partials: Vec<PathBuf> = exports.iter().map(|(path, _)| partial_path(path)).collect();
+    let written = write_partials(&mut reader, &filename, mode, bins, exports, &partials);
+    let report = match written {
+        Ok(report) => report,
+        Err(e) => {
+            for partial in &partials {
+                let _ = fs::remove_file(partial);
+            }
+            return Err(e);
+        }
+    };
+
+    for (partial, (path, _)) in partials.iter().zip(exports) {
+        fs::rename(partial, path).map_err(|e| AocError::from(e).in_file(path.to_string_lossy()))?;
+    }
+    Ok(report)
+}
+
+fn write_partials(
+    reader: &mut dyn std::io::BufRead,
+    filename: &str,
+    mode: Mode,
+    bins: usize,
+    exports: &[(PathBuf, Format)],
+    partials: &[PathBuf],
+) -> AocResult<Report> {
+    let mut writers = vec![];
+    for (partial, &(_, format)) in partials.iter().zip(exports) {
+        let in_export = |e: AocError| e.in_file(partial.to_string_lossy());
+        let file = File::create(partial).map_err(|e| in_export(e.into()))?;
+        let rows = RowWriter::new(BufWriter::new(file), format).map_err(in_export)?;
+        writers.push((partial, rows));
+    }
+
+    let mut summary = Summary::default();
+    for_each_elf(reader, mode, |elf| {
+        summary.add(&elf);
+        for (partial, rows) in &mut writers {
+            rows.write(&elf)
+                .map_err(|e| e.in_file(partial.to_string_lossy()))?;
+        }
+        Ok(())
+    })
+    .map_err(|e| e.in_file(filename))?;
+
+    let report = summary
+        .report(bins)
+        .ok_or_else(|| AocError::solve("there are no elves").in_file(filename))?;
+    for (partial, rows) in writers {
+        rows.finish()
+            .map_err(|e| e.in_file(partial.to_string_lossy()))?;
+    }
+    Ok(report)
+}
+
+#[test]
+fn run_stats_test() {
+    let dir = crate::test_server::temp_dir("stats");
+    let csv = dir.join("elves.csv");
+    let json = dir.join("elves.json");
+    fs::write(&csv, "old").unwrap();
+    let exports = [(csv.clone(), Format::Csv), (json.clone(), Format::Json)];
+
+    let good = dir.join("good.txt");
+    fs::write(&good, "1\n2\n\n4\n").unwrap();
+    let report = run_stats(&good, Mode::Strict, 2, &exports).unwrap();
+    assert_eq!(report.elves, 2);
+    assert_eq!(
+        fs::read_to_string(&csv).unwrap(),
+        "elf,items,heaviest,calories\n0,2,2,3\n1,1,4,4\n"
+    );
+    assert!(fs::read_to_string(&json).unwrap().starts_with("[\n"));
+
+    // Neither a bad line part way through nor a missing input touches them.
+    let bad = dir.join("bad.txt");
+    fs::write(&bad, "1\n\nx\n").unwrap();
+    let e = run_stats(&bad, Mode::Strict, 2, &exports).unwrap_err();
+    assert_eq!(e.line, Some(3));
+    assert!(run_stats(&dir.join("missing.txt"), Mode::Strict, 2, &exports).is_err());
+    assert!(fs::read_to_string(&csv).unwrap().ends_with("1,1,4,4\n"));
+    let mut files: Vec<_> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    files.sort();
+    assert_eq!(files, ["bad.txt", "elves.csv", "elves.json", "good.txt"]);
+}
@@ -0,0 +1,362 @@
+use std::{fmt::Display, time::Duration};
+
+use aoc_common::{AocError, AocResult};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::Client,
+    config::Config,
+    guesses::{Guess, Guesses},
+};
+
+/// How long the site makes you wait after a wrong answer, unless it says otherwise.
+const WRONG_ANSWER_WAIT: Duration = Duration::from_secs(60);
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    WrongAnswer,
+    /// The answer was not looked at; try again after the wait. These are
+    /// never recorded as guesses.
+    #[serde(skip)]
+    RateLimited(Duration),
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::WrongAnswer => write!(f, "wrong answer"),
+            Verdict::RateLimited(wait) => {
+                write!(f, "rate limited, wait {}", format_wait(wait.as_secs()))
+            }
+        }
+    }
+}
+
+/// Submit `answer` for one part, unless the recorded guesses already say how
+/// it would go. Refuses known wrong answers and anything sent while the site
+/// is still making us wait, and warns about answers outside the known
+/// too-high/too-low bounds before sending them. `now` is the current Unix
+/// time.
+pub fn submit_answer(
+    config: &Config,
+    day: u8,
+    part: u8,
+    answer: &str,
+    now: u64,
+) -> AocResult<Verdict> {
+    let path = config.guesses_file();
+    let mut guesses = Guesses::load(&path)?;
+
+    if let Some(known) = check_guess(&guesses, day, part, answer, now)? {
+        return Ok(known);
+    }
+    if let Some(warning) = bounds_warning(&guesses, day, part, answer) {
+        eprintln!("warning: {}; submitting it anyway", warning);
+    }
+
+    let page = Client::new(config)?.post_answer(day, part, answer)?;
+    let verdict = parse_response(&page)?;
+
+    let wait = match verdict {
+        Verdict::Correct => None,
+        Verdict::RateLimited(wait) => Some(wait),
+        _ => Some(wrong_answer_wait(&article_text(&page)).unwrap_or(WRONG_ANSWER_WAIT)),
+    };
+    guesses.wait_until = wait.map(|wait| now + wait.as_secs());
+
+    if !matches!(verdict, Verdict::RateLimited(_)) {
+        guesses.record(Guess {
+            day,
+            part,
+            answer: answer.to_owned(),
+            verdict,
+            at: now,
+        });
+    }
+    guesses.save(&path)?;
+
+    Ok(verdict)
+}
+
+/// `Ok(Some(_))` when the answer is already known to be right, `Err` when it
+/// should not be sent.
+fn check_guess(
+    guesses: &Guesses,
+    day: u8,
+    part: u8,
+    answer: &str,
+    now: u64,
+) -> AocResult<Option<Verdict>> {
+    if let Some(solved) = guesses.solved(day, part) {
+        if solved.answer == answer {
+            return Ok(Some(Verdict::Correct));
+        }
+        return Err(AocError::solve(format!(
+            "day {} part {} was already solved with {}, not {}",
+            day, part, solved.answer, answer
+        )));
+    }
+
+    if let Some(rejected) = guesses.rejected(day, part, answer) {
+        return Err(AocError::solve(format!(
+            "{} was already rejected for day {} part {} ({})",
+            answer, day, part, rejected.verdict
+        )));
+    }
+
+    if let Some(until) = guesses.wait_until.filter(|&until| until > now) {
+        return Err(AocError::solve(format!(
+            "the site is still throttling answers, wait {}",
+            format_wait(until - now)
+        )));
+    }
+
+    Ok(None)
+}
+
+/// Why a numeric `answer` cannot be right, if an earlier guess already puts
+/// it out of bounds.
+fn bounds_warning(guesses: &Guesses, day: u8, part: u8, answer: &str) -> Option<String> {
+    let value = answer.parse::<i128>().ok()?;
+    let reason = match guesses.bounds(day, part) {
+        (Some(low), _) if value <= low => format!("{} was too low", low),
+        (_, Some(high)) if value >= high => format!("{} was too high", high),
+        _ => return None,
+    };
+    Some(format!("{} cannot be right, {}", answer, reason))
+}
+
+/// Read the verdict out of the page the site sends back for an answer.
+pub fn parse_response(page: &str) -> AocResult<Verdict> {
+    let text = article_text(page);
+
+    if text.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if text.contains("You gave an answer too recently") {
+        let wait = rate_limit_wait(&text).ok_or_else(|| {
+            AocError::network(format!("could not find the wait time in `{}`", text))
+        })?;
+        Ok(Verdict::RateLimited(wait))
+    } else if text.contains("That's not the right answer") {
+        Ok(if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::WrongAnswer
+        })
+    } else if text.contains("You don't seem to be solving the right level") {
+        Err(AocError::network(
+            "the site is not expecting an answer for that part; is it already solved?",
+        ))
+    } else {
+        Err(AocError::network(format!(
+            "did not understand the response `{}`",
+            text
+        )))
+    }
+}
+
+/// The readable text of the page's `<article>`, tags stripped and whitespace
+/// collapsed.
+fn article_text(page: &str) -> String {
+    let article = match (page.find("<article"), page.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &page[start..end],
+        _ => page,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// "You have 4m 52s left to wait."
+fn rate_limit_wait(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+
+    let mut seconds = 0;
+    for token in wait.split_whitespace() {
+        let unit = match token.chars().last()? {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        seconds += token[..token.len() - 1].parse::<u64>().ok()? * unit;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// "Please wait one minute before trying again." or "please wait 5 minutes ...".
+fn wrong_answer_wait(text: &str) -> Option<Duration> {
+    let lower = text.to_lowercase();
+    let (_, rest) = lower.split_once("please wait ")?;
+    match rest.split_whitespace().collect::<Vec<_>>()[..] {
+        ["one", "minute", ..] => Some(Duration::from_secs(60)),
+        [count, unit, ..] if unit.starts_with("minute") => {
+            Some(Duration::from_secs(count.parse::<u64>().ok()? * 60))
+        }
+        _ => None,
+    }
+}
+
+fn format_wait(seconds: u64) -> String {
+    if seconds >= 60 {
+        format!("{}m {}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+#[cfg(test)]
+const TOO_HIGH: &str = r#"<main>
+<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href="/2022/about">about page</a>, or you can ask for hints on the <a href="https://www.reddit.com/r/adventofcode/" target="_blank">subreddit</a>.  Please wait one minute before trying again. [<a href="/2022/day/1">Return to Day 1</a>]</p></article>
+</main>"#;
+
+#[cfg(test)]
+const CORRECT: &str = r#"<main>
+<article><p>That's the right answer!  You are <span class="day-success">one gold star</span> closer to collecting enough star fruit. [<a href="/2022/day/1#part2">Continue to Part Two</a>]</p></article>
+</main>"#;
+
+#[test]
+fn parse_response_test() {
+    assert_eq!(parse_response(CORRECT), Ok(Verdict::Correct));
+    assert_eq!(parse_response(TOO_HIGH), Ok(Verdict::TooHigh));
+    assert_eq!(
+        parse_response(
+            "<article><p>That's not the right answer; your answer is too low.</p></article>"
+        ),
+        Ok(Verdict::TooLow)
+    );
+    assert_eq!(
+        parse_response(
+            "<article><p>That's not the right answer.  If you're stuck...</p></article>"
+        ),
+        Ok(Verdict::WrongAnswer)
+    );
+    assert_eq!(
+        parse_response("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 52s left to wait. [<a href=\"/2022/day/1\">Return to Day 1</a>]</p></article>"),
+        Ok(Verdict::RateLimited(Duration::from_secs(292)))
+    );
+    assert!(parse_response("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>").is_err());
+    assert!(parse_response("<html>Something else</html>").is_err());
+}
+
+#[test]
+fn wrong_answer_wait_test() {
+    assert_eq!(
+        wrong_answer_wait(&article_text(TOO_HIGH)),
+        Some(Duration::from_secs(60))
+    );
+    assert_eq!(
+        wrong_answer_wait("Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again."),
+        Some(Duration::from_secs(300))
+    );
+}
+
+#[cfg(test)]
+use crate::test_server::{temp_dir, StubServer};
+
+#[test]
+fn submit_records_and_refuses_guesses_test() {
+    let server = StubServer::start(vec![(200, TOO_HIGH), (200, TOO_HIGH), (200, CORRECT)]);
+    let config = Config::for_test(&server.base_url, temp_dir("submit"));
+
+    assert_eq!(
+        submit_answer(&config, 1, 1, "500", 1000),
+        Ok(Verdict::TooHigh)
+    );
+    let requests = server.requests();
+    assert!(requests[0].starts_with("POST /2022/day/1/answer HTTP/1.1"));
+    assert!(requests[0].ends_with("level=1&answer=500"));
+
+    // Still inside the one minute penalty.
+    let e = submit_answer(&config, 1, 1, "400", 1030).unwrap_err();
+    assert!(e.message.contains("30s"), "{}", e.message);
+
+    let e = submit_answer(&config, 1, 1, "500", 2000).unwrap_err();
+    assert!(e.message.contains("already rejected"), "{}", e.message);
+
+    // Above a known too-high answer: warned about, but sent all the same.
+    assert_eq!(
+        submit_answer(&config, 1, 1, "600", 2000),
+        Ok(Verdict::TooHigh)
+    );
+    assert_eq!(server.requests().len(), 2);
+
+    assert_eq!(
+        submit_answer(&config, 1, 1, "400", 3000),
+        Ok(Verdict::Correct)
+    );
+    // Known to be right, so it is not sent again.
+    assert_eq!(
+        submit_answer(&config, 1, 1, "400", 4000),
+        Ok(Verdict::Correct)
+    );
+    assert_eq!(server.requests().len(), 3);
+
+    let guesses = Guesses::load(&config.guesses_file()).unwrap();
+    assert_eq!(guesses.guesses.len(), 3);
+    assert_eq!(guesses.wait_until, None);
+}
+
+#[test]
+fn submit_rate_limited_test() {
+    let server = StubServer::start(vec![(
+        200,
+        "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 36s left to wait.</p></article>",
+    )]);
+    let config = Config::for_test(&server.base_url, temp_dir("submit-limited"));
+
+    assert_eq!(
+        submit_answer(&config, 2, 1, "15", 100),
+        Ok(Verdict::RateLimited(Duration::from_secs(36)))
+    );
+
+    let guesses = Guesses::load(&config.guesses_file()).unwrap();
+    assert!(guesses.guesses.is_empty());
+    assert_eq!(guesses.wait_until, Some(136));
+}
+
+#[test]
+fn bounds_warning_test() {
+    let mut guesses = Guesses::default();
+    for (answer, verdict) in [("10", Verdict::TooLow), ("30", Verdict::TooHigh)] {
+        guesses.record(Guess {
+            day: 1,
+            part: 1,
+            answer: answer.to_owned(),
+            verdict,
+            at: 0,
+        });
+    }
+
+    assert_eq!(
+        bounds_warning(&guesses, 1, 1, "10").as_deref(),
+        Some("10 cannot be right, 10 was too low")
+    );
+    assert_eq!(
+        bounds_warning(&guesses, 1, 1, "31").as_deref(),
+        Some("31 cannot be right, 30 was too high")
+    );
+    assert_eq!(bounds_warning(&guesses, 1, 1, "20"), None);
+    assert_eq!(bounds_warning(&guesses, 1, 2, "31"), None);
+    assert_eq!(bounds_warning(&guesses, 1, 1, "abc"), None);
+}
@@ -0,0 +1,143 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{registry::Day, AocError};
+
+use crate::answers::Answers;
+
+/// How one part compared with its recorded answer.
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Match,
+    Mismatch {
+        expected: String,
+    },
+    /// Nothing is recorded for this part yet.
+    Missing,
+    Failed(AocError),
+}
+
+impl Status {
+    /// How `value` compares with the answer it should have been, if known.
+    pub fn of(value: &str, expected: Option<&str>) -> Status {
+        match expected {
+            Some(expected) if expected == value => Status::Match,
+            Some(expected) => Status::Mismatch {
+                expected: expected.to_owned(),
+            },
+            None => Status::Missing,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub value: Option<String>,
+    pub status: Status,
+    /// Whether the recorded answer is one the site accepted.
+    pub confirmed: bool,
+    pub elapsed: Duration,
+}
+
+impl Check {
+    /// A mismatch or an error; missing answers only get reported.
+    pub fn is_failure(&self) -> bool {
+        matches!(self.status, Status::Mismatch { .. } | Status::Failed(_))
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day {:>2}, part {}: ", self.day, self.part)?;
+        let value = self.value.as_deref().unwrap_or_default();
+        match &self.status {
+            Status::Match => write!(f, "{} ok", value)?,
+            Status::Mismatch { expected } => {
+                write!(f, "{} MISMATCH, expected {}", value, expected)?
+            }
+            Status::Missing => write!(f, "{} (no recorded answer)", value)?,
+            Status::Failed(e) => write!(f, "FAILED {}", e)?,
+        }
+        let compared = matches!(self.status, Status::Match | Status::Mismatch { .. });
+        if compared && !self.confirmed {
+            write!(f, " (unconfirmed)")?;
+        }
+        write!(f, " [{:.1?}]", self.elapsed)
+    }
+}
+
+/// Solve each part of `day` from `input` on its own, timing it (parsing
+/// included), and compare the result with the registry.
+pub fn verify_day(day: &Day, input: &str, answers: &Answers) -> Vec<Check> {
+    [1, 2]
+        .into_iter()
+        .map(|part| {
+            let start = Instant::now();
+            let result = day.solve(input, &[part]);
+            let elapsed = start.elapsed();
+
+            let (value, status) = match result {
+                Ok(mut solved) => {
+                    let value = solved.remove(0).value;
+                    let status = Status::of(&value, answers.get(day.number, part));
+                    (Some(value), status)
+                }
+                Err(e) => (None, Status::Failed(e)),
+            };
+
+            Check {
+                day: day.number,
+                part,
+                value,
+                status,
+                confirmed: answers.is_confirmed(day.number, part),
+                elapsed,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+use std::path::Path;
+
+#[cfg(test)]
+use crate::days::find_day;
+
+#[test]
+fn verify_day_test() {
+    let mut answers = Answers::default();
+    answers.set(2, 1, "15");
+    answers.set(2, 2, "13");
+    let input = std::fs::read_to_string("../day2/test2.txt").unwrap();
+
+    let checks = verify_day(find_day(2).unwrap().unwrap(), &input, &answers);
+    assert_eq!(checks[0].status, Status::Match);
+    assert_eq!(
+        checks[1].status,
+        Status::Mismatch {
+            expected: "13".to_owned()
+        }
+    );
+    assert!(!checks[0].is_failure());
+    assert!(checks[1].is_failure());
+    assert!(checks[1]
+        .to_string()
+        .starts_with("Day  2, part 2: 12 MISMATCH, expected 13 ["));
+
+    let unconfirmed = Answers::load(Path::new("../answers.toml")).unwrap();
+    let checks = verify_day(find_day(2).unwrap().unwrap(), &input, &unconfirmed);
+    assert!(matches!(checks[0].status, Status::Mismatch { .. }));
+    assert!(!checks[0].confirmed);
+    assert!(checks[0].to_string().contains(" (unconfirmed) ["));
+
+    let checks = verify_day(find_day(4).unwrap().unwrap(), "x", &Answers::default());
+    assert!(matches!(checks[0].status, Status::Failed(_)));
+
+    let checks = verify_day(find_day(2).unwrap().unwrap(), &input, &Answers::default());
+    assert_eq!(checks[0].status, Status::Missing);
+    assert!(!checks[0].is_failure());
+}
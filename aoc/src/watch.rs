@@ -0,0 +1,235 @@
+//! `aoc watch`: poll a day's input and example files and re-run both parts
+//! whenever one of them changes.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime},
+};
+
+use aoc_common::{registry::Day, AocResult, Input};
+
+/// What a file looked like when last polled; any difference counts as a
+/// change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Stamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+/// One part's answer, or why it has none, and how long it took.
+#[derive(Debug, Clone)]
+struct PartRun {
+    answer: AocResult<String>,
+    elapsed: Duration,
+}
+
+/// Both parts of a day over one file, or the error from reading or parsing
+/// it.
+type FileRun = AocResult<Vec<PartRun>>;
+
+pub struct Watcher<'a> {
+    day: &'a Day,
+    /// The day's crate, whose `.txt` files are its input and examples.
+    dir: PathBuf,
+    /// Watched as well when `dir` has no `input.txt`, as `aoc run` would
+    /// fall back to it.
+    fallback: PathBuf,
+    stamps: BTreeMap<PathBuf, Stamp>,
+    runs: BTreeMap<PathBuf, FileRun>,
+}
+
+impl<'a> Watcher<'a> {
+    pub fn new(day: &'a Day, dir: PathBuf, fallback: PathBuf) -> Watcher<'a> {
+        Watcher {
+            day,
+            dir,
+            fallback,
+            stamps: BTreeMap::new(),
+            runs: BTreeMap::new(),
+        }
+    }
+
+    /// The files being watched right now, by name, so that new examples are
+    /// picked up as they are written.
+    fn files(&self) -> Vec<PathBuf> {
+        let mut files: Vec<PathBuf> = fs::read_dir(&self.dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "txt") && path.is_file())
+            .collect();
+        if !files.iter().any(|path| path.ends_with("input.txt")) && self.fallback.exists() {
+            files.push(self.fallback.clone());
+        }
+        files.sort();
+        files
+    }
+
+    /// Re-run every file if any of them changed (or appeared, or went away)
+    /// since the last poll, and describe the new answers against the old.
+    pub fn poll(&mut self) -> Option<String> {
+        let stamps: BTreeMap<PathBuf, Stamp> = self
+            .files()
+            .into_iter()
+            .filter_map(|path| {
+                let metadata = fs::metadata(&path).ok()?;
+                let stamp = Stamp {
+                    modified: metadata.modified().ok(),
+                    len: metadata.len(),
+                };
+                Some((path, stamp))
+            })
+            .collect();
+        if stamps == self.stamps {
+            return None;
+        }
+
+        let mut out = String::new();
+        let mut runs = BTreeMap::new();
+        for path in stamps.keys() {
+            let changed = !self.stamps.is_empty() && self.stamps.get(path) != stamps.get(path);
+            let run = run_file(self.day, path);
+            write_run(&mut out, path, changed, &run, self.runs.get(path));
+            runs.insert(path.clone(), run);
+        }
+        if stamps.is_empty() {
+            writeln!(out, "No input or examples in {}", self.dir.display()).unwrap();
+        }
+
+        self.stamps = stamps;
+        self.runs = runs;
+        Some(out)
+    }
+
+    /// Poll every `interval`, printing each change's results, until killed.
+    pub fn run(&mut self, interval: Duration) -> ! {
+        loop {
+            if let Some(report) = self.poll() {
+                println!("{}", report);
+            }
+            std::thread::sleep(interval);
+        }
+    }
+}
+
+fn run_file(day: &Day, path: &Path) -> FileRun {
+    let filename = path.to_string_lossy();
+    let input = Input::read(&filename)?;
+    let parsed = day.parse(&input).map_err(|e| e.in_file(filename.clone()))?;
+
+    Ok([1, 2]
+        .into_iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = parsed.part(part);
+            PartRun {
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect())
+}
+
+fn write_run(
+    out: &mut String,
+    path: &Path,
+    changed: bool,
+    run: &FileRun,
+    previous: Option<&FileRun>,
+) {
+    let mark = if changed { " (changed)" } else { "" };
+    writeln!(out, "{}{}", path.display(), mark).unwrap();
+
+    let parts = match run {
+        Ok(parts) => parts,
+        Err(e) => {
+            writeln!(out, "  FAILED {}", e).unwrap();
+            return;
+        }
+    };
+    for (i, part) in parts.iter().enumerate() {
+        let before = match previous {
+            Some(Ok(before)) => before.get(i),
+            _ => None,
+        };
+        write!(out, "  part {}: ", i + 1).unwrap();
+        match &part.answer {
+            Ok(answer) => write!(out, "{}", answer).unwrap(),
+            Err(e) => write!(out, "FAILED {}", e).unwrap(),
+        }
+        if let Some(was) = before.and_then(|before| answer_change(&before.answer, &part.answer)) {
+            write!(out, " (was {})", was).unwrap();
+        }
+
+        write!(out, "  [{:.1?}", part.elapsed).unwrap();
+        if let Some(before) = before.filter(|before| !before.elapsed.is_zero()) {
+            let change = (part.elapsed.as_secs_f64() / before.elapsed.as_secs_f64() - 1.0) * 100.0;
+            write!(out, ", {:+.1}%", change).unwrap();
+        }
+        writeln!(out, "]").unwrap();
+    }
+}
+
+/// The old answer, if it differs from the new one.
+fn answer_change(before: &AocResult<String>, now: &AocResult<String>) -> Option<String> {
+    match (before, now) {
+        (Ok(before), Ok(now)) if before == now => None,
+        (Err(_), Err(_)) => None,
+        (Ok(before), _) => Some(before.clone()),
+        (Err(_), Ok(_)) => Some("an error".to_owned()),
+    }
+}
+
+#[cfg(test)]
+use crate::days::find_day;
+
+#[test]
+fn watcher_test() {
+    let dir = crate::test_server::temp_dir("watch");
+    let example = fs::read_to_string("../day2/test2.txt").unwrap();
+    fs::write(dir.join("test.txt"), &example).unwrap();
+    fs::write(dir.join("notes.md"), "not watched").unwrap();
+    let fallback = dir.join("cache").join("day2.txt");
+
+    let mut watcher = Watcher::new(find_day(2).unwrap().unwrap(), dir.clone(), fallback);
+    let report = watcher.poll().unwrap();
+    let lines: Vec<_> = report.lines().collect();
+    assert_eq!(lines[0], dir.join("test.txt").display().to_string());
+    assert!(lines[1].starts_with("  part 1: 15  ["));
+    assert!(lines[2].starts_with("  part 2: 12  ["));
+    assert_eq!(lines.len(), 3);
+    assert!(watcher.poll().is_none());
+
+    fs::write(dir.join("test.txt"), format!("{}A Y\n", example)).unwrap();
+    let report = watcher.poll().unwrap();
+    let lines: Vec<_> = report.lines().collect();
+    assert!(lines[0].ends_with("test.txt (changed)"));
+    assert!(lines[1].starts_with("  part 1: 23 (was 15)  ["));
+    assert!(lines[1].ends_with("%]"));
+
+    fs::write(dir.join("input.txt"), "A Q\n").unwrap();
+    let report = watcher.poll().unwrap();
+    assert!(report.contains("input.txt (changed)\n  FAILED "));
+    assert!(report.contains("test.txt\n  part 1: 23  ["));
+}
+
+#[test]
+fn fallback_test() {
+    let dir = crate::test_server::temp_dir("watch-fallback");
+    let fallback = dir.join("day2.txt");
+    fs::write(&fallback, "A Y\n").unwrap();
+    let crate_dir = dir.join("day2");
+    fs::create_dir(&crate_dir).unwrap();
+
+    let mut watcher = Watcher::new(find_day(2).unwrap().unwrap(), crate_dir.clone(), fallback);
+    assert!(watcher.poll().unwrap().contains("day2.txt\n  part 1: 8  ["));
+
+    fs::write(crate_dir.join("input.txt"), "A Y\n").unwrap();
+    let report = watcher.poll().unwrap();
+    assert!(!report.contains("day2.txt"));
+    assert!(report.contains("input.txt (changed)"));
+}
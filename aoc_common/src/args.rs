@@ -0,0 +1,16 @@
+use std::path::PathBuf;
+
+/// The input file for a day: the one given on the command line, or the
+/// `input.txt` sitting in that day's crate.
+pub fn input_path(day: u8, input: Option<PathBuf>) -> PathBuf {
+    input.unwrap_or_else(|| PathBuf::from(format!("day{}/input.txt", day)))
+}
+
+#[test]
+fn input_path_test() {
+    assert_eq!(input_path(7, None), PathBuf::from("day7/input.txt"));
+    assert_eq!(
+        input_path(7, Some(PathBuf::from("other.txt"))),
+        PathBuf::from("other.txt")
+    );
+}
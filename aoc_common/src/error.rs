@@ -0,0 +1,195 @@
+use std::{fmt::Display, io};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The input could not be read at all.
+    Io,
+    /// The input was read but is not what the puzzle describes.
+    Parse,
+    /// The input parsed, but the puzzle has no answer for it.
+    Solve,
+    /// Settings such as the session token are missing or malformed.
+    Config,
+    /// Talking to the puzzle site failed.
+    Network,
+}
+
+/// What went wrong, and as much of where as we know. Parsers usually only
+/// know the line and column; the runner fills in the file name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    pub kind: ErrorKind,
+    pub message: String,
+    pub file: Option<String>,
+    /// 1-based line number in the input.
+    pub line: Option<usize>,
+    /// 1-based column (in characters) within that line.
+    pub column: Option<usize>,
+    /// The offending line, or token when the line is not known.
+    pub text: Option<String>,
+}
+
+pub type AocResult<T> = Result<T, AocError>;
+
+impl AocError {
+    fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        AocError {
+            kind,
+            message: message.into(),
+            file: None,
+            line: None,
+            column: None,
+            text: None,
+        }
+    }
+
+    pub fn io(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Io, message)
+    }
+
+    pub fn parse(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Parse, message)
+    }
+
+    pub fn solve(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Solve, message)
+    }
+
+    pub fn config(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Config, message)
+    }
+
+    pub fn network(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Network, message)
+    }
+
+    pub fn in_file(mut self, file: impl Into<String>) -> Self {
+        self.file.get_or_insert(file.into());
+        self
+    }
+
+    /// Record the line this error came from, unless a more specific one was
+    /// already given.
+    pub fn at_line(mut self, line: usize, text: &str) -> Self {
+        if self.line.is_none() {
+            self.line = Some(line);
+            self.text = Some(text.to_owned());
+        }
+        self
+    }
+
+    /// Record just the line number, for code that no longer has the text.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    /// The error came from a piece of the line starting at `start_column`, so
+    /// any column it already has is relative to that piece.
+    pub fn at_offset(mut self, start_column: usize) -> Self {
+        self.column = Some(match self.column {
+            Some(column) => start_column + column.saturating_sub(1),
+            None => start_column,
+        });
+        self
+    }
+
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.text.get_or_insert(text.to_owned());
+        self
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let location: Vec<String> = [
+            self.file.clone(),
+            self.line.map(|l| l.to_string()),
+            self.column.map(|c| c.to_string()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(":"))?;
+        }
+
+        let kind = match self.kind {
+            ErrorKind::Io => "read error",
+            ErrorKind::Parse => "parse error",
+            ErrorKind::Solve => "error",
+            ErrorKind::Config => "config error",
+            ErrorKind::Network => "network error",
+        };
+        write!(f, "{}: {}", kind, self.message)?;
+
+        if let Some(text) = &self.text {
+            write!(f, "\n    {}", text)?;
+            if let (Some(column), Some(_)) = (self.column, self.line) {
+                // Columns count from 1, but a 0 should not take the message down.
+                write!(f, "\n    {}^", " ".repeat(column.saturating_sub(1)))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for AocError {}
+
+impl From<io::Error> for AocError {
+    fn from(e: io::Error) -> Self {
+        AocError::io(e.to_string())
+    }
+}
+
+#[test]
+fn display_test() {
+    let e = AocError::parse("expected a number")
+        .at_column(3)
+        .at_line(2, "1 x 3")
+        .in_file("input.txt");
+    assert_eq!(
+        e.to_string(),
+        "input.txt:2:3: parse error: expected a number\n    1 x 3\n      ^"
+    );
+
+    assert_eq!(AocError::solve("no elves").to_string(), "error: no elves");
+    assert_eq!(
+        AocError::parse("unknown move").with_text("Q").to_string(),
+        "parse error: unknown move\n    Q"
+    );
+    assert_eq!(
+        AocError::parse("bad")
+            .at_column(0)
+            .at_line(1, "ab")
+            .to_string(),
+        "1:0: parse error: bad\n    ab\n    ^"
+    );
+}
+
+#[test]
+fn at_offset_test() {
+    assert_eq!(AocError::parse("bad").at_offset(5).column, Some(5));
+    assert_eq!(
+        AocError::parse("bad").at_column(3).at_offset(5).column,
+        Some(7)
+    );
+    assert_eq!(
+        AocError::parse("bad").at_column(0).at_offset(5).column,
+        Some(5)
+    );
+}
+
+#[test]
+fn at_line_keeps_the_innermost_location_test() {
+    let e = AocError::parse("bad")
+        .at_line(3, "inner")
+        .at_line(9, "outer");
+    assert_eq!(e.line, Some(3));
+    assert_eq!(e.text.as_deref(), Some("inner"));
+}
@@ -0,0 +1,91 @@
+//! Seeded, made-up puzzle inputs, for stress tests and benchmarks bigger than
+//! the one real input. Each day registers its generator with
+//! `#[aoc_generator(day = N)]`.
+
+use std::ops::RangeInclusive;
+
+/// SplitMix64: small and fast, and the same seed gives the same input on
+/// every machine, which is the point of a seed.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`. Slightly biased for huge ranges, which does not
+    /// matter for made-up inputs.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (low, high) = range.into_inner();
+        assert!(low <= high, "empty range {}..={}", low, high);
+        match (high - low).checked_add(1) {
+            Some(span) => low + ((self.next_u64() as u128 * span as u128) >> 64) as u64,
+            None => self.next_u64(),
+        }
+    }
+
+    /// An index into something `len` long.
+    pub fn index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "no index into an empty slice");
+        self.range(0..=len as u64 - 1) as usize
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    /// True `numerator` times in `denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.range(1..=denominator) <= numerator
+    }
+
+    /// Fisher-Yates.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// A generated input, with both answers when the generator knows them by
+/// construction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub answers: Option<[String; 2]>,
+}
+
+#[test]
+fn rng_test() {
+    let mut a = Rng::new(7);
+    let mut b = Rng::new(7);
+    let first: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+    assert_eq!(first, (0..5).map(|_| b.next_u64()).collect::<Vec<_>>());
+    assert_ne!(Rng::new(8).next_u64(), first[0]);
+    // Pinned, so that a seed keeps meaning the same input.
+    assert_eq!(Rng::new(0).next_u64(), 0xe220a8397b1dcdaf);
+
+    let mut rng = Rng::new(1);
+    let rolls: Vec<u64> = (0..1000).map(|_| rng.range(3..=5)).collect();
+    assert!(rolls.iter().all(|roll| (3..=5).contains(roll)));
+    assert!([3, 4, 5].iter().all(|n| rolls.contains(n)));
+    assert_eq!(rng.range(9..=9), 9);
+    rng.range(0..=u64::MAX);
+
+    let mut items: Vec<u32> = (0..20).collect();
+    rng.shuffle(&mut items);
+    assert_ne!(items, (0..20).collect::<Vec<_>>());
+    items.sort();
+    assert_eq!(items, (0..20).collect::<Vec<_>>());
+}
@@ -0,0 +1,258 @@
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufRead, Read},
+    ops::Deref,
+};
+
+use crate::{
+    error::{AocError, AocResult},
+    parse::is_blank,
+};
+
+/// The filename that means standard input.
+pub const STDIN: &str = "-";
+
+/// A buffered reader over the input file, or over stdin for `-`.
+pub fn open_input(filename: &str) -> AocResult<Box<dyn BufRead>> {
+    if filename == STDIN {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+
+    let file = File::open(filename).map_err(|e| AocError::from(e).in_file(filename))?;
+    Ok(Box::new(io::BufReader::new(file)))
+}
+
+/// The whole input file (or stdin) as one string.
+pub fn read_input(filename: &str) -> AocResult<String> {
+    let mut input = String::new();
+    open_input(filename)?
+        .read_to_string(&mut input)
+        .map_err(|e| AocError::from(e).in_file(filename))?;
+    Ok(input)
+}
+
+/// A whole input read into one buffer, which lends out its lines and
+/// sections as slices instead of copying each into a `String`. Parsers take
+/// it as a `&str`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Input {
+    text: String,
+}
+
+impl Input {
+    /// The input file, or stdin for `-`.
+    pub fn read(filename: &str) -> AocResult<Input> {
+        read_input(filename).map(Input::from)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.text.lines()
+    }
+
+    pub fn sections(&self) -> Sections<'_> {
+        Sections::new(&self.text)
+    }
+}
+
+impl From<String> for Input {
+    fn from(text: String) -> Self {
+        Input { text }
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// A run of non-blank lines, and the line number it starts on so that
+/// errors inside it can still point at the right line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub first_line: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Section<'a> {
+    pub fn lines(&self) -> std::str::Lines<'a> {
+        self.text.lines()
+    }
+}
+
+/// The blank-line separated sections of some text, in order, as
+/// `parse::blank_line_groups` would split its lines.
+#[derive(Debug, Clone)]
+pub struct Sections<'a> {
+    text: &'a str,
+    /// Where the next line starts, and its number counting from 1.
+    offset: usize,
+    line: usize,
+}
+
+impl<'a> Sections<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Sections {
+            text,
+            offset: 0,
+            line: 1,
+        }
+    }
+}
+
+impl<'a> Iterator for Sections<'a> {
+    type Item = Section<'a>;
+
+    fn next(&mut self) -> Option<Section<'a>> {
+        let mut start = None;
+        let mut end = self.offset;
+        while self.offset < self.text.len() {
+            let rest = &self.text[self.offset..];
+            let len = rest.find('\n').map_or(rest.len(), |i| i + 1);
+            let line = rest[..len].trim_end();
+            let (line_start, number) = (self.offset, self.line);
+            self.offset += len;
+            self.line += 1;
+
+            if !is_blank(line) {
+                start.get_or_insert((line_start, number));
+                end = line_start + line.len();
+            } else if start.is_some() {
+                break;
+            }
+        }
+
+        let (start, first_line) = start?;
+        Some(Section {
+            first_line,
+            text: &self.text[start..end],
+        })
+    }
+}
+
+/// The lines of a reader, read one at a time as they are asked for. Reading
+/// stops at the first error, which `finish` hands back.
+pub struct InputLines<R> {
+    reader: R,
+    line: usize,
+    error: Option<AocError>,
+}
+
+impl<R: BufRead> InputLines<R> {
+    pub fn new(reader: R) -> Self {
+        InputLines {
+            reader,
+            line: 0,
+            error: None,
+        }
+    }
+
+    /// The error that stopped reading early, if there was one.
+    pub fn finish(self) -> AocResult<()> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for InputLines<R> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if self.error.is_some() {
+            return None;
+        }
+
+        self.line += 1;
+        let mut line = String::new();
+        match self.reader.read_line(&mut line) {
+            Ok(0) => None,
+            Ok(_) => {
+                if line.ends_with('\n') {
+                    line.pop();
+                    if line.ends_with('\r') {
+                        line.pop();
+                    }
+                }
+                Some(line)
+            }
+            Err(e) => {
+                self.error = Some(AocError::from(e).on_line(self.line));
+                None
+            }
+        }
+    }
+}
+
+#[test]
+fn input_test() {
+    let input = Input::read("./test.txt").unwrap();
+    let lines: Vec<_> = input.lines().collect();
+    assert_eq!(lines, vec!["1,2", "3,4", "", "a b  c"]);
+    // Lent straight out of the buffer.
+    assert_eq!(lines[1].as_ptr(), input[4..].as_ptr());
+    assert!(input.ends_with("a b  c\n"));
+}
+
+#[test]
+fn input_missing_test() {
+    let e = Input::read("./does-not-exist.txt").unwrap_err();
+    assert_eq!(e.kind, crate::error::ErrorKind::Io);
+    assert_eq!(e.file.as_deref(), Some("./does-not-exist.txt"));
+}
+
+#[test]
+fn read_input_test() {
+    assert_eq!(read_input("./test.txt").unwrap(), "1,2\n3,4\n\na b  c\n");
+}
+
+#[test]
+fn input_lines_test() {
+    let mut lines = InputLines::new("a\r\nb\n\nc".as_bytes());
+    assert_eq!((&mut lines).collect::<Vec<_>>(), vec!["a", "b", "", "c"]);
+    assert!(lines.finish().is_ok());
+
+    let mut lines = InputLines::new(&[b'a', b'\n', 0xff, b'\n'][..]);
+    assert_eq!((&mut lines).collect::<Vec<_>>(), vec!["a"]);
+    assert_eq!(lines.finish().unwrap_err().line, Some(2));
+}
+
+#[test]
+fn sections_test() {
+    let input = Input::read("./test.txt").unwrap();
+    let sections: Vec<_> = input.sections().collect();
+    assert_eq!(
+        sections,
+        [
+            Section {
+                first_line: 1,
+                text: "1,2\n3,4"
+            },
+            Section {
+                first_line: 4,
+                text: "a b  c"
+            },
+        ]
+    );
+    assert_eq!(sections[1].lines().collect::<Vec<_>>(), ["a b  c"]);
+
+    let sections: Vec<_> = Sections::new("\n\na\r\n\r\n \r\nb\nc").collect();
+    assert_eq!(sections[0].text, "a");
+    assert_eq!((sections[1].first_line, sections[1].text), (6, "b\nc"));
+    assert_eq!(Sections::new("").next(), None);
+    assert_eq!(Sections::new("\n\n").next(), None);
+}
@@ -0,0 +1,21 @@
+//! Code shared by every day: finding the input, reading it, the small
+//! parsing helpers that kept getting copied from crate to crate, and the
+//! `Solution` trait the runner drives.
+
+pub mod args;
+pub mod error;
+pub mod generate;
+pub mod input;
+pub mod parse;
+pub mod registry;
+pub mod solution;
+pub mod template;
+
+pub use args::input_path;
+pub use error::{AocError, AocResult};
+pub use generate::{Generated, Rng};
+pub use input::{open_input, read_input, Input, InputLines, Section, Sections, STDIN};
+/// For the code `aoc_macros` generates.
+#[doc(hidden)]
+pub use inventory;
+pub use solution::{solve, solve_reader, Answer, Solution};
@@ -0,0 +1,277 @@
+use std::{any::type_name, io::BufRead, str::FromStr};
+
+use crate::{
+    error::{AocError, AocResult},
+    input::InputLines,
+};
+
+/// A line with nothing but whitespace on it, which separates records.
+pub fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// Split a list of lines into the runs separated by blank lines. Runs of
+/// several blank lines, and blank lines at either end, make no empty groups.
+pub fn blank_line_groups<T: AsRef<str>>(lines: &[T]) -> impl Iterator<Item = &[T]> {
+    lines
+        .split(|line| is_blank(line.as_ref()))
+        .filter(|group| !group.is_empty())
+}
+
+/// `blank_line_groups` for lines as they come, such as `InputLines` from a
+/// reader: each record is collected once its blank line (or the end) is
+/// reached, and the lines before it are never held on to.
+pub struct Records<I> {
+    lines: I,
+    /// How many lines have been read so far.
+    line: usize,
+}
+
+/// The lines of one record, which run on from `first_line` (counting from 1)
+/// with no gaps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record<T> {
+    pub first_line: usize,
+    pub lines: Vec<T>,
+}
+
+impl<T> Record<T> {
+    /// Each line with its line number.
+    pub fn numbered(&self) -> impl Iterator<Item = (usize, &T)> {
+        (self.first_line..).zip(&self.lines)
+    }
+}
+
+impl<I> Records<I> {
+    pub fn new(lines: I) -> Self {
+        Records { lines, line: 0 }
+    }
+}
+
+impl<I> Iterator for Records<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = Record<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut lines = vec![];
+        let mut first_line = 0;
+        for line in self.lines.by_ref() {
+            self.line += 1;
+            if !is_blank(line.as_ref()) {
+                if lines.is_empty() {
+                    first_line = self.line;
+                }
+                lines.push(line);
+            } else if !lines.is_empty() {
+                break;
+            }
+        }
+        // The end of the input ends the last record too.
+        Some(Record { first_line, lines }).filter(|record| !record.lines.is_empty())
+    }
+}
+
+/// Parse every line of `input` with `parse_line`, tagging any error with the
+/// line it came from. What `parse_line` returns may borrow from `input`.
+pub fn parse_lines<'a, T, F>(input: &'a str, mut parse_line: F) -> AocResult<Vec<T>>
+where
+    F: FnMut(&'a str) -> AocResult<T>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_line(line).map_err(|e| e.at_line(i + 1, line)))
+        .collect()
+}
+
+/// `parse_lines` for a reader, parsing each line as it is read instead of
+/// loading the whole input first.
+pub fn parse_lines_from<R, T, F>(reader: R, mut parse_line: F) -> AocResult<Vec<T>>
+where
+    R: BufRead,
+    F: FnMut(&str) -> AocResult<T>,
+{
+    let mut lines = InputLines::new(reader);
+    let parsed = (&mut lines)
+        .enumerate()
+        .map(|(i, line)| parse_line(&line).map_err(|e| e.at_line(i + 1, &line)))
+        .collect::<AocResult<Vec<_>>>()?;
+    lines.finish()?;
+    Ok(parsed)
+}
+
+/// Parse a single token, with an error that names the expected type.
+pub fn parse_token<T: FromStr>(token: &str) -> AocResult<T> {
+    token.parse().map_err(|_| {
+        let name = type_name::<T>().rsplit("::").next().unwrap_or_default();
+        AocError::parse(format!("expected {}, found `{}`", name, token)).with_text(token)
+    })
+}
+
+/// The 1-based column at which `part`, a slice of `line`, starts.
+pub fn column_of(line: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize)
+        .checked_sub(line.as_ptr() as usize)
+        .filter(|offset| offset + part.len() <= line.len())
+        .unwrap_or(0);
+    line[..offset].chars().count() + 1
+}
+
+/// Parse something like `498,4` (or `2-4`) into a pair of values.
+pub fn parse_pair<T: FromStr>(s: &str, separator: char) -> AocResult<(T, T)> {
+    pair_with(s, separator, |part| parse_token(part.trim()))
+}
+
+/// Split `s` in two at `separator` and parse each half with `parse_half`,
+/// with errors placed in the half they came from.
+pub fn pair_with<T, F>(s: &str, separator: char, mut parse_half: F) -> AocResult<(T, T)>
+where
+    F: FnMut(&str) -> AocResult<T>,
+{
+    let (first, second) = s.split_once(separator).ok_or_else(|| {
+        AocError::parse(format!("expected a pair separated by `{}`", separator)).with_text(s)
+    })?;
+
+    let first = parse_half(first).map_err(|e| e.at_offset(column_of(s, first)))?;
+    let second = parse_half(second).map_err(|e| e.at_offset(column_of(s, second)))?;
+    Ok((first, second))
+}
+
+/// Parse each `separator` separated item of `s` (trimmed) with `parse_item`,
+/// with errors placed in the item they came from.
+pub fn separated<T, F>(s: &str, separator: &str, mut parse_item: F) -> AocResult<Vec<T>>
+where
+    F: FnMut(&str) -> AocResult<T>,
+{
+    s.split(separator)
+        .map(str::trim)
+        .map(|item| parse_item(item).map_err(|e| e.at_offset(column_of(s, item))))
+        .collect()
+}
+
+/// A list of numbers like `1, 2, 3`.
+pub fn numbers<T: FromStr>(s: &str, separator: &str) -> AocResult<Vec<T>> {
+    separated(s, separator, parse_token)
+}
+
+/// The whitespace separated tokens of a line.
+pub fn tokens(line: &str) -> Vec<&str> {
+    line.split_whitespace().collect()
+}
+
+#[test]
+fn blank_line_groups_test() {
+    let lines = vec!["1", "2", "", "3", "", "4"];
+    let groups: Vec<_> = blank_line_groups(&lines).collect();
+    assert_eq!(groups, vec![&["1", "2"][..], &["3"], &["4"]]);
+
+    let lines = vec!["", "1", " ", "", "\t", "2 ", ""];
+    let groups: Vec<_> = blank_line_groups(&lines).collect();
+    assert_eq!(groups, vec![&["1"][..], &["2 "]]);
+}
+
+#[test]
+fn records_test() {
+    let records: Vec<_> = Records::new("1\n2\n\n3\n\n4".lines())
+        .map(|record| record.lines)
+        .collect();
+    assert_eq!(records, vec![vec!["1", "2"], vec!["3"], vec!["4"]]);
+
+    let input = "\n1\r\n  \n\n\t\n2 \n3\n\n";
+    let mut lines = InputLines::new(input.as_bytes());
+    let records: Vec<_> = Records::new(&mut lines).collect();
+    assert_eq!(records[0].first_line, 2);
+    assert_eq!(records[1].first_line, 6);
+    assert_eq!(
+        records[1].numbered().collect::<Vec<_>>(),
+        [(6, &"2 ".to_owned()), (7, &"3".to_owned())]
+    );
+    let records: Vec<_> = records.into_iter().map(|record| record.lines).collect();
+    assert_eq!(records, vec![vec!["1"], vec!["2 ", "3"]]);
+    assert!(lines.finish().is_ok());
+    let lines: Vec<_> = input.lines().collect();
+    assert_eq!(
+        records,
+        blank_line_groups(&lines)
+            .map(|group| group.to_vec())
+            .collect::<Vec<_>>()
+    );
+
+    assert_eq!(Records::new("".lines()).next(), None);
+    assert_eq!(Records::new("\n \n".lines()).next(), None);
+}
+
+#[test]
+fn parse_lines_test() {
+    assert_eq!(parse_lines("1\n2", parse_token::<u8>).unwrap(), vec![1, 2]);
+
+    let e = parse_lines("1\n2\nx", parse_token::<u8>).unwrap_err();
+    assert_eq!(e.line, Some(3));
+    assert_eq!(e.text.as_deref(), Some("x"));
+    assert_eq!(e.message, "expected u8, found `x`");
+}
+
+#[test]
+fn parse_lines_from_test() {
+    let input = "1\n2\nx\n";
+    assert_eq!(
+        parse_lines_from("1\n2\n".as_bytes(), parse_token::<u8>),
+        Ok(vec![1, 2])
+    );
+    assert_eq!(
+        parse_lines_from(input.as_bytes(), parse_token::<u8>),
+        parse_lines(input, parse_token::<u8>)
+    );
+}
+
+#[test]
+fn column_of_test() {
+    let line = "498,4 -> 498,6";
+    assert_eq!(column_of(line, &line[9..]), 10);
+    let unrelated = String::from("unrelated");
+    assert_eq!(column_of(line, &unrelated), 1);
+}
+
+#[test]
+fn parse_pair_test() {
+    assert_eq!(parse_pair::<usize>("498,4", ','), Ok((498, 4)));
+    assert_eq!(parse_pair::<i32>("2-4", '-'), Ok((2, 4)));
+    assert_eq!(parse_pair::<i32>("2-x", '-').unwrap_err().column, Some(3));
+    assert!(parse_pair::<i32>("24", '-').is_err());
+}
+
+#[test]
+fn pair_with_test() {
+    assert_eq!(
+        pair_with("2-4,6-8", ',', |half| parse_pair::<u8>(half, '-')),
+        Ok(((2, 4), (6, 8)))
+    );
+    let e = pair_with("2-4,6-x", ',', |half| parse_pair::<u8>(half, '-')).unwrap_err();
+    assert_eq!(e.column, Some(7));
+}
+
+#[test]
+fn separated_test() {
+    assert_eq!(numbers::<u32>("1, 2,3", ","), Ok(vec![1, 2, 3]));
+    assert_eq!(numbers::<u32>("1, x", ",").unwrap_err().column, Some(4));
+    assert_eq!(
+        separated("1,2 -> 3,4", " -> ", |p| parse_pair::<u8>(p, ',')),
+        Ok(vec![(1, 2), (3, 4)])
+    );
+    assert_eq!(
+        separated("1,2 -> 3;4", " -> ", |p| parse_pair::<u8>(p, ','))
+            .unwrap_err()
+            .column,
+        Some(8)
+    );
+    assert!(numbers::<u32>("1,,2", ",").is_err());
+}
+
+#[test]
+fn tokens_test() {
+    assert_eq!(tokens("$ cd  a"), vec!["$", "cd", "a"]);
+    assert!(tokens("").is_empty());
+}
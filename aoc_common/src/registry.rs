@@ -0,0 +1,241 @@
+//! Where `#[aoc]` puts each day's `Solution` and `#[aoc_generator]` its
+//! generator, and where the runner finds them again. Each day's parsed input
+//! is kept behind `dyn Parsed`, so every day can share the one table.
+
+use std::{collections::BTreeMap, io::BufRead};
+
+use crate::{
+    error::{AocError, AocResult},
+    generate::{Generated, Rng},
+    solution::{self, Answer, Solution},
+};
+
+/// A day's parsed input, which can answer either part. It may borrow from the
+/// text it was parsed from.
+pub trait Parsed {
+    fn part(&self, part: u8) -> AocResult<String>;
+}
+
+struct ParsedInput<'a, S: Solution>(S::Input<'a>);
+
+impl<S: Solution> Parsed for ParsedInput<'_, S> {
+    fn part(&self, part: u8) -> AocResult<String> {
+        solution::part::<S>(&self.0, part)
+    }
+}
+
+/// A `Solution` registered with `#[aoc(day = N)]`.
+pub struct Solver {
+    pub day: u8,
+    pub name: &'static str,
+    parse: for<'a> fn(&'a str) -> AocResult<Box<dyn Parsed + 'a>>,
+    solve_reader: fn(&mut dyn BufRead, &[u8]) -> AocResult<Vec<Answer>>,
+}
+
+impl Solver {
+    pub const fn new<S: Solution + 'static>(day: u8, name: &'static str) -> Solver {
+        Solver {
+            day,
+            name,
+            parse: parse::<S>,
+            solve_reader: solution::solve_reader::<S>,
+        }
+    }
+}
+
+fn parse<S: Solution + 'static>(input: &str) -> AocResult<Box<dyn Parsed + '_>> {
+    Ok(Box::new(ParsedInput::<S>(S::parse(input)?)))
+}
+
+/// A function registered with `#[aoc_generator(day = N)]`, which makes up an
+/// input of roughly `size` records.
+pub struct Generator {
+    pub day: u8,
+    pub name: &'static str,
+    pub generate: fn(&mut Rng, usize) -> Generated,
+}
+
+inventory::collect!(Solver);
+inventory::collect!(Generator);
+
+/// Everything registered for one day.
+#[derive(Default)]
+pub struct Day {
+    pub number: u8,
+    solver: Option<&'static Solver>,
+    generator: Option<&'static Generator>,
+}
+
+impl Day {
+    /// Parse `input`, keeping whatever the day's input type borrows from it.
+    pub fn parse<'a>(&self, input: &'a str) -> AocResult<Box<dyn Parsed + 'a>> {
+        (self.solver()?.parse)(input)
+    }
+
+    /// Parse `input` and answer the requested parts.
+    pub fn solve(&self, input: &str, parts: &[u8]) -> AocResult<Vec<Answer>> {
+        let parsed = self.parse(input)?;
+        parts
+            .iter()
+            .map(|&part| {
+                Ok(Answer {
+                    part,
+                    value: parsed.part(part)?,
+                })
+            })
+            .collect()
+    }
+
+    /// `solve`, reading the input from `reader`, as it is read if the day
+    /// can do that.
+    pub fn solve_reader(&self, reader: &mut dyn BufRead, parts: &[u8]) -> AocResult<Vec<Answer>> {
+        (self.solver()?.solve_reader)(reader, parts)
+    }
+
+    /// A made-up input of roughly `size` records; the same seed always gives
+    /// the same input.
+    pub fn generate(&self, seed: u64, size: usize) -> AocResult<Generated> {
+        match self.generator {
+            Some(generator) => Ok((generator.generate)(&mut Rng::new(seed), size)),
+            None => Err(self.missing("generator")),
+        }
+    }
+
+    fn solver(&self) -> AocResult<&'static Solver> {
+        self.solver.ok_or_else(|| self.missing("solution"))
+    }
+
+    fn missing(&self, what: &str) -> AocError {
+        AocError::solve(format!("day {} has no {} registered", self.number, what))
+    }
+}
+
+/// Every registered day, in order. Two things registered for the same day is
+/// an error, as it is not clear which one is meant.
+pub fn days() -> AocResult<Vec<Day>> {
+    let mut days: BTreeMap<u8, Day> = BTreeMap::new();
+    fn day(days: &mut BTreeMap<u8, Day>, number: u8) -> &mut Day {
+        days.entry(number).or_insert_with(|| Day {
+            number,
+            ..Day::default()
+        })
+    }
+    let clash = |what: String, first: &str, second: &str| {
+        AocError::solve(format!(
+            "{} is registered twice, by `{}` and `{}`",
+            what, first, second
+        ))
+    };
+
+    for solver in inventory::iter::<Solver> {
+        let day = day(&mut days, solver.day);
+        if let Some(existing) = day.solver.replace(solver) {
+            return Err(clash(
+                format!("the solution for day {}", solver.day),
+                existing.name,
+                solver.name,
+            ));
+        }
+    }
+
+    for generator in inventory::iter::<Generator> {
+        let day = day(&mut days, generator.day);
+        if let Some(existing) = day.generator.replace(generator) {
+            return Err(clash(
+                format!("the generator for day {}", generator.day),
+                existing.name,
+                generator.name,
+            ));
+        }
+    }
+
+    Ok(days.into_values().collect())
+}
+
+/// The registered day `number`, for a day's own tests, which only see what
+/// their crate registers.
+pub fn day(number: u8) -> AocResult<Day> {
+    days()?
+        .into_iter()
+        .find(|day| day.number == number)
+        .ok_or_else(|| AocError::solve(format!("day {} is not registered", number)))
+}
+
+/// Sums its lines, and keeps the last one as a slice of the input.
+#[cfg(test)]
+struct Lines;
+
+#[cfg(test)]
+impl Solution for Lines {
+    type Input<'a> = (Vec<i32>, &'a str);
+    type Part1 = i32;
+    type Part2 = String;
+
+    fn parse(input: &str) -> AocResult<Self::Input<'_>> {
+        let numbers = crate::parse::parse_lines(input, crate::parse::parse_token)?;
+        Ok((numbers, input.lines().last().unwrap_or_default()))
+    }
+
+    fn part1((numbers, _): &Self::Input<'_>) -> AocResult<Self::Part1> {
+        Ok(numbers.iter().sum())
+    }
+
+    fn part2((_, last): &Self::Input<'_>) -> AocResult<Self::Part2> {
+        Ok(last.to_string())
+    }
+}
+
+#[cfg(test)]
+inventory::submit! {
+    Solver::new::<Lines>(25, "Lines")
+}
+
+#[cfg(test)]
+fn count_up(rng: &mut Rng, size: usize) -> Generated {
+    let start = rng.range(0..=9);
+    let numbers: Vec<String> = (start..start + size as u64)
+        .map(|n| n.to_string())
+        .collect();
+    Generated {
+        input: numbers.join("\n"),
+        answers: None,
+    }
+}
+
+#[cfg(test)]
+inventory::submit! {
+    Generator { day: 25, name: "count_up", generate: count_up }
+}
+
+#[test]
+fn registered_day_test() {
+    let day = day(25).unwrap();
+
+    assert_eq!(
+        day.solve("1\n2\n3\n", &[1]),
+        Ok(vec![Answer {
+            part: 1,
+            value: "6".to_owned()
+        }])
+    );
+    assert_eq!(
+        day.solve_reader(&mut "4\n5\n".as_bytes(), &[1]).unwrap()[0].value,
+        "9"
+    );
+    assert_eq!(day.solve("1\nx\n", &[1]).unwrap_err().line, Some(2));
+    assert_eq!(day.solve("1\n2\n", &[2]).unwrap()[0].value, "2");
+
+    let input = "7\n8\n".to_owned();
+    let parsed = day.parse(&input).unwrap();
+    assert_eq!(parsed.part(1), Ok("15".to_owned()));
+    assert_eq!(parsed.part(2), Ok("8".to_owned()));
+
+    assert!(day.solve("1\n", &[3]).is_err());
+
+    let generated = day.generate(3, 4).unwrap();
+    assert_eq!(generated, day.generate(3, 4).unwrap());
+    assert_eq!(generated.input.lines().count(), 4);
+    assert!(day.solve(&generated.input, &[1]).is_ok());
+
+    assert!(crate::registry::day(24).is_err());
+}
@@ -0,0 +1,150 @@
+use std::{fmt::Display, io::BufRead};
+
+use crate::error::{AocError, AocResult};
+
+/// A day's puzzle: parse the input once, then answer both parts from it.
+pub trait Solution {
+    /// The parsed input, which may borrow from the text it was parsed from.
+    type Input<'a>;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> AocResult<Self::Input<'_>>;
+    fn part1(input: &Self::Input<'_>) -> AocResult<Self::Part1>;
+    fn part2(input: &Self::Input<'_>) -> AocResult<Self::Part2>;
+
+    /// Parse straight from a reader, for days that can work through their
+    /// input as it arrives. The rest return `None` without reading anything,
+    /// and have their input read in full and handed to `parse`.
+    fn parse_reader(_reader: &mut dyn BufRead) -> Option<AocResult<Self::Input<'static>>> {
+        None
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub part: u8,
+    pub value: String,
+}
+
+/// Parse `input` and answer the requested parts.
+pub fn solve<S: Solution>(input: &str, parts: &[u8]) -> AocResult<Vec<Answer>> {
+    answer::<S>(&S::parse(input)?, parts)
+}
+
+/// `solve`, reading the input from `reader`.
+pub fn solve_reader<S: Solution>(reader: &mut dyn BufRead, parts: &[u8]) -> AocResult<Vec<Answer>> {
+    if let Some(parsed) = S::parse_reader(reader) {
+        return answer::<S>(&parsed?, parts);
+    }
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    solve::<S>(&input, parts)
+}
+
+/// Answer one part from what `S::parse` returned.
+pub fn part<S: Solution>(parsed: &S::Input<'_>, part: u8) -> AocResult<String> {
+    match part {
+        1 => Ok(S::part1(parsed)?.to_string()),
+        2 => Ok(S::part2(parsed)?.to_string()),
+        _ => Err(AocError::solve(format!("there is no part {}", part))),
+    }
+}
+
+fn answer<S: Solution>(parsed: &S::Input<'_>, parts: &[u8]) -> AocResult<Vec<Answer>> {
+    parts
+        .iter()
+        .map(|&part| {
+            Ok(Answer {
+                part,
+                value: self::part::<S>(parsed, part)?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+struct Sum;
+
+#[cfg(test)]
+impl Solution for Sum {
+    type Input<'a> = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> AocResult<Self::Input<'_>> {
+        crate::parse::parse_lines(input, crate::parse::parse_token)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> AocResult<Self::Part1> {
+        Ok(input.iter().sum())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> AocResult<Self::Part2> {
+        Ok(input.len())
+    }
+}
+
+/// Keeps its input's words as slices of it.
+#[cfg(test)]
+struct Words;
+
+#[cfg(test)]
+impl Solution for Words {
+    type Input<'a> = Vec<&'a str>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> AocResult<Self::Input<'_>> {
+        Ok(input.split_whitespace().collect())
+    }
+
+    fn part1(words: &Self::Input<'_>) -> AocResult<Self::Part1> {
+        Ok(words.len())
+    }
+
+    fn part2(words: &Self::Input<'_>) -> AocResult<Self::Part2> {
+        words
+            .iter()
+            .max_by_key(|word| word.len())
+            .map(|word| word.to_string())
+            .ok_or_else(|| AocError::solve("there are no words"))
+    }
+}
+
+#[test]
+fn solve_test() {
+    assert_eq!(
+        solve::<Sum>("1\n2\n3", &[1, 2]),
+        Ok(vec![
+            Answer {
+                part: 1,
+                value: "6".to_owned()
+            },
+            Answer {
+                part: 2,
+                value: "3".to_owned()
+            }
+        ])
+    );
+    assert_eq!(solve::<Words>("a bcd ef", &[2]).unwrap()[0].value, "bcd");
+}
+
+#[test]
+fn solve_errors_test() {
+    assert_eq!(solve::<Sum>("1\nx", &[1]).unwrap_err().line, Some(2));
+    assert!(solve::<Sum>("1", &[3]).is_err());
+    assert!(solve::<Words>("", &[2]).is_err());
+}
+
+#[test]
+fn solve_reader_test() {
+    assert_eq!(
+        solve_reader::<Sum>(&mut "4\n5\n".as_bytes(), &[1]),
+        solve::<Sum>("4\n5\n", &[1])
+    );
+    assert_eq!(
+        solve_reader::<Words>(&mut "a bcd ef".as_bytes(), &[1]).unwrap()[0].value,
+        "3"
+    );
+}
@@ -0,0 +1,184 @@
+//! Parse a line against a template such as `move {} from {} to {}`: the
+//! text between the `{}` must match exactly, and each `{}` is parsed into the
+//! matching element of a tuple.
+
+use crate::{
+    error::{AocError, AocResult},
+    parse::{column_of, parse_token},
+};
+
+/// `template::parse`, as in `let (count, from, to) = parse!("move {} from {} to {}", line)?;`
+/// with the types taken from where the values are used.
+#[macro_export]
+macro_rules! parse {
+    ($template:literal, $line:expr) => {
+        $crate::template::parse($template, $line)
+    };
+}
+
+/// What one `{}` can be parsed into: a number, `char`, `bool` or `String`,
+/// or a `&str` borrowed straight from the line.
+pub trait FromCapture<'a>: Sized {
+    fn from_capture(capture: &'a str) -> AocResult<Self>;
+}
+
+impl<'a> FromCapture<'a> for &'a str {
+    fn from_capture(capture: &'a str) -> AocResult<Self> {
+        Ok(capture)
+    }
+}
+
+// Not every `FromStr` type: a blanket impl would overlap the `&str` one.
+macro_rules! from_str_capture {
+    ($($ty:ty),+) => {
+        $(
+            impl<'a> FromCapture<'a> for $ty {
+                fn from_capture(capture: &'a str) -> AocResult<Self> {
+                    parse_token(capture)
+                }
+            }
+        )+
+    };
+}
+
+from_str_capture!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+from_str_capture!(char, bool, String);
+
+/// A tuple that can be filled from a template's captures.
+pub trait FromCaptures<'a>: Sized {
+    fn from_captures(line: &'a str, captures: &[&'a str]) -> AocResult<Self>;
+}
+
+macro_rules! tuple_from_captures {
+    ($len:literal: $($field:ident $index:tt),+) => {
+        impl<'a, $($field: FromCapture<'a>),+> FromCaptures<'a> for ($($field,)+) {
+            fn from_captures(line: &'a str, captures: &[&'a str]) -> AocResult<Self> {
+                if captures.len() != $len {
+                    return Err(AocError::parse(format!(
+                        "the template has {} fields but {} were asked for",
+                        captures.len(),
+                        $len
+                    )));
+                }
+                Ok(($(field::<$field>(line, captures[$index])?,)+))
+            }
+        }
+    };
+}
+
+tuple_from_captures!(1: A 0);
+tuple_from_captures!(2: A 0, B 1);
+tuple_from_captures!(3: A 0, B 1, C 2);
+tuple_from_captures!(4: A 0, B 1, C 2, D 3);
+tuple_from_captures!(5: A 0, B 1, C 2, D 3, E 4);
+tuple_from_captures!(6: A 0, B 1, C 2, D 3, E 4, F 5);
+
+fn field<'a, T: FromCapture<'a>>(line: &'a str, capture: &'a str) -> AocResult<T> {
+    T::from_capture(capture).map_err(|e| e.at_offset(column_of(line, capture)))
+}
+
+/// Where a line stopped following its template.
+struct Mismatch {
+    column: usize,
+}
+
+/// Split `line` into the text for each `{}` of `template`. Each capture runs
+/// up to the first place the literal text after it appears; the last one
+/// runs to the end of the line.
+fn match_template<'a>(template: &str, line: &'a str) -> Result<Vec<&'a str>, Mismatch> {
+    let literals: Vec<&str> = template.split("{}").collect();
+    assert!(
+        literals.len() < 3
+            || literals[1..literals.len() - 1]
+                .iter()
+                .all(|l| !l.is_empty()),
+        "`{}` has two `{{}}` in a row, so where one ends is ambiguous",
+        template
+    );
+    let mismatch = |rest: &str| Mismatch {
+        column: column_of(line, rest),
+    };
+
+    let mut rest = line
+        .strip_prefix(literals[0])
+        .ok_or_else(|| mismatch(line))?;
+    let mut captures = Vec::with_capacity(literals.len() - 1);
+    for (i, literal) in literals.iter().enumerate().skip(1) {
+        let end = if i == literals.len() - 1 {
+            match rest.strip_suffix(literal) {
+                Some(capture) => capture.len(),
+                None => return Err(mismatch(rest)),
+            }
+        } else {
+            rest.find(literal).ok_or_else(|| mismatch(rest))?
+        };
+        if end == 0 {
+            return Err(mismatch(rest));
+        }
+        captures.push(&rest[..end]);
+        rest = &rest[end + literal.len()..];
+    }
+    if !rest.is_empty() {
+        return Err(mismatch(rest));
+    }
+    Ok(captures)
+}
+
+/// Match `line` against `template` and parse each `{}` into `T`.
+pub fn parse<'a, T: FromCaptures<'a>>(template: &str, line: &'a str) -> AocResult<T> {
+    let captures = match_template(template, line)
+        .map_err(|m| AocError::parse(format!("expected `{}`", template)).at_column(m.column))?;
+    T::from_captures(line, &captures)
+}
+
+/// `parse`, or `None` if `line` does not have the template's shape at all,
+/// for trying one template after another.
+pub fn try_parse<'a, T: FromCaptures<'a>>(template: &str, line: &'a str) -> Option<AocResult<T>> {
+    let captures = match_template(template, line).ok()?;
+    Some(T::from_captures(line, &captures))
+}
+
+#[test]
+fn parse_test() {
+    assert_eq!(
+        parse("move {} from {} to {}", "move 13 from 1 to 2"),
+        Ok((13, 1, 2))
+    );
+    assert_eq!(
+        parse("{},{} -> {}", "4,x -> y"),
+        Ok(("4".to_owned(), 'x', "y".to_owned()))
+    );
+    assert_eq!(parse("$ cd {}", "$ cd a b"), Ok(("a b".to_owned(),)));
+    assert_eq!(parse("{} {}", "14 a.txt"), Ok((14, "a.txt")));
+
+    let e = parse::<(u8, u8, u8)>("move {} from {} to {}", "move 1 from x to 2").unwrap_err();
+    assert_eq!(e.message, "expected u8, found `x`");
+    assert_eq!(e.column, Some(13));
+
+    let e = parse::<(u8, u8)>("{}-{}", "2,4").unwrap_err();
+    assert_eq!(e.message, "expected `{}-{}`");
+    assert_eq!(e.column, Some(1));
+    assert_eq!(
+        parse::<(u8, u8)>("move {} to {}", "move 1 at 2")
+            .unwrap_err()
+            .column,
+        Some(6)
+    );
+    assert!(parse::<(u8,)>("{}!", "1").is_err());
+    assert!(parse::<(u8,)>("dir {}", "dir ").is_err());
+    assert!(parse::<(u8, u8)>("{}", "1").is_err());
+}
+
+#[test]
+fn try_parse_test() {
+    assert_eq!(try_parse("dir {}", "dir a"), Some(Ok(("a".to_owned(),))));
+    assert_eq!(try_parse::<(String,)>("dir {}", "14 a"), None);
+
+    let line = "dir a".to_owned();
+    let (name,): (&str,) = try_parse("dir {}", &line).unwrap().unwrap();
+    assert_eq!(name.as_ptr(), line[4..].as_ptr());
+    assert!(matches!(
+        try_parse::<(u8,)>("dir {}", "dir a"),
+        Some(Err(_))
+    ));
+}
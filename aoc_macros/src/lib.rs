@@ -0,0 +1,111 @@
+//! `#[aoc(day = N)]` and `#[aoc_generator(day = N)]`: register a day's
+//! `Solution` and generator with `aoc_common::registry`, so the runner finds
+//! them without a hand-written table.
+//!
+//! `#[aoc]` goes on the day's `impl Solution for DayN` block. A generator
+//! takes `&mut Rng` and a size and returns a `Generated` input.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::{parse::Parser, parse_macro_input, spanned::Spanned, Error, ItemFn, ItemImpl, LitInt};
+
+/// Register a day's `impl Solution` block as the solution to that day.
+#[proc_macro_attribute]
+pub fn aoc(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let block = parse_macro_input!(item as ItemImpl);
+    expand_solution(attr.into(), block)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Register a function as the generator of made-up inputs for a day.
+#[proc_macro_attribute]
+pub fn aoc_generator(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let function = parse_macro_input!(item as ItemFn);
+    expand_generator(attr.into(), function)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn parse_day(attr: TokenStream2) -> syn::Result<u8> {
+    let mut day = None;
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("day") {
+            day = Some(in_range(&meta.value()?.parse::<LitInt>()?, 1, 25)?);
+            Ok(())
+        } else {
+            Err(meta.error("unknown argument"))
+        }
+    });
+    parser.parse2(attr.clone())?;
+
+    day.ok_or_else(|| Error::new(attr.span(), "missing `day = N`"))
+}
+
+fn in_range(lit: &LitInt, min: u8, max: u8) -> syn::Result<u8> {
+    let value = lit.base10_parse::<u8>()?;
+    if value < min || value > max {
+        return Err(Error::new(
+            lit.span(),
+            format!("expected {} to {}", min, max),
+        ));
+    }
+    Ok(value)
+}
+
+fn expand_solution(attr: TokenStream2, block: ItemImpl) -> syn::Result<TokenStream2> {
+    let day = parse_day(attr)?;
+    let is_solution = block
+        .trait_
+        .as_ref()
+        .and_then(|(_, path, _)| path.segments.last())
+        .is_some_and(|segment| segment.ident == "Solution");
+    if !is_solution {
+        return Err(Error::new(
+            block.span(),
+            "`#[aoc]` goes on an `impl Solution for ...` block",
+        ));
+    }
+    if !block.generics.params.is_empty() {
+        return Err(Error::new(
+            block.generics.span(),
+            "a registered solution cannot be generic",
+        ));
+    }
+    let ty = &block.self_ty;
+    let name = ty.to_token_stream().to_string();
+
+    Ok(quote! {
+        #block
+
+        ::aoc_common::inventory::submit! {
+            ::aoc_common::registry::Solver::new::<#ty>(#day, #name)
+        }
+    })
+}
+
+fn expand_generator(attr: TokenStream2, function: ItemFn) -> syn::Result<TokenStream2> {
+    let day = parse_day(attr)?;
+    if !function.sig.generics.params.is_empty() {
+        return Err(Error::new(
+            function.sig.generics.span(),
+            "a registered generator cannot be generic",
+        ));
+    }
+    let ident = &function.sig.ident;
+    let name = ident.to_string();
+
+    // The signature is checked by the field's type, `fn(&mut Rng, usize) -> Generated`.
+    Ok(quote! {
+        #function
+
+        ::aoc_common::inventory::submit! {
+            ::aoc_common::registry::Generator {
+                day: #day,
+                name: #name,
+                generate: #ident,
+            }
+        }
+    })
+}
@@ -0,0 +1,26 @@
+use aoc_common::{Generated, Rng};
+use aoc_macros::aoc_generator;
+
+/// `size` elves (at least three), each carrying a handful of snacks.
+#[aoc_generator(day = 1)]
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut groups = vec![];
+    let mut totals = vec![];
+    for _ in 0..size.max(3) {
+        let snacks: Vec<u64> = (0..rng.range(1..=15))
+            .map(|_| rng.range(1000..=60000))
+            .collect();
+        totals.push(snacks.iter().sum::<u64>());
+        let lines: Vec<String> = snacks.iter().map(u64::to_string).collect();
+        groups.push(lines.join("\n"));
+    }
+
+    totals.sort_unstable_by(|a, b| b.cmp(a));
+    Generated {
+        input: groups.join("\n\n") + "\n",
+        answers: Some([
+            totals[0].to_string(),
+            totals[..3].iter().sum::<u64>().to_string(),
+        ]),
+    }
+}
@@ -0,0 +1,524 @@
+mod generate;
+pub mod stats;
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::fmt::{Debug, Display};
+use std::io::BufRead;
+use std::num::IntErrorKind;
+
+use aoc_common::{
+    parse::{column_of, Records},
+    AocError, AocResult, InputLines, Solution,
+};
+use aoc_macros::aoc;
+use tracing::warn;
+
+#[cfg(test)]
+use aoc_common::{read_input, Input};
+
+/// Turns lines into elves one blank-line separated record at a time, so the
+/// lines can come straight from a reader.
+struct ElfMaker<I> {
+    next_id: u32,
+    records: Records<I>,
+    mode: Mode,
+}
+
+/// What to do with a calorie entry that is not a number, or that pushes an
+/// elf's total past `u64`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// Fail, naming the elf and the line.
+    #[default]
+    Strict,
+    /// Log a warning and leave the entry out of the elf's total.
+    Lenient,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    pub number: u32,
+    /// The total of `items`.
+    pub calories: u64,
+    /// The calories of each item, in the order they were listed.
+    pub items: Vec<u64>,
+}
+
+impl Elf {
+    pub fn item_count(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn heaviest_item(&self) -> Option<u64> {
+        self.items.iter().max().copied()
+    }
+
+    /// The sum behind the total, like `elf 3: 1000 + 2000 = 3000`.
+    pub fn breakdown(&self) -> String {
+        let items: Vec<String> = self.items.iter().map(u64::to_string).collect();
+        let items = match items.is_empty() {
+            true => "nothing".to_owned(),
+            false => items.join(" + "),
+        };
+        format!("elf {}: {} = {}", self.number, items, self.calories)
+    }
+}
+
+/// Greater is better: more calories, and on a tie the elf that came first.
+impl Ord for Elf {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.calories
+            .cmp(&other.calories)
+            .then_with(|| other.number.cmp(&self.number))
+            .then_with(|| self.items.cmp(&other.items))
+    }
+}
+
+impl PartialOrd for Elf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// An elf as the calorie list would show it, an item to a line.
+impl Display for Elf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let items: Vec<String> = self.items.iter().map(u64::to_string).collect();
+        write!(f, "{}", items.join("\n"))
+    }
+}
+
+/// The elf with the single heaviest item, and that item. The first such elf
+/// wins a tie.
+pub fn heaviest_item(elves: &[Elf]) -> Option<(&Elf, u64)> {
+    elves
+        .iter()
+        .filter_map(|elf| Some((elf, elf.heaviest_item()?)))
+        .min_by_key(|&(elf, item)| (Reverse(item), elf.number))
+}
+
+/// The elves carrying more than `count` items, in order.
+pub fn carrying_more_than(elves: &[Elf], count: usize) -> impl Iterator<Item = &Elf> {
+    elves.iter().filter(move |elf| elf.item_count() > count)
+}
+
+/// The calorie list for `elves`, which parses back to the same elves as
+/// long as none of them is empty-handed.
+pub fn write_elves(elves: &[Elf]) -> String {
+    elves
+        .iter()
+        .map(|elf| format!("{}\n", elf))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl<'a, T: AsRef<str>> ElfMaker<std::slice::Iter<'a, T>> {
+    #[cfg(test)]
+    fn new(lines: &'a [T]) -> Self {
+        Self::from_lines(lines.iter(), Mode::Strict)
+    }
+}
+
+impl<I> ElfMaker<I> {
+    fn from_lines(lines: I, mode: Mode) -> Self {
+        ElfMaker {
+            next_id: 0,
+            records: Records::new(lines),
+            mode,
+        }
+    }
+}
+
+impl<I> Iterator for ElfMaker<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = AocResult<Elf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = self.records.next()?;
+        let number = self.next_id;
+        self.next_id += 1;
+
+        let mut calories: u64 = 0;
+        let mut items = Vec::with_capacity(record.lines.len());
+        for (line_number, line) in record.numbered() {
+            let line = line.as_ref();
+            let entry = line.trim();
+            let total = entry
+                .parse::<u64>()
+                .map_err(|e| match e.kind() {
+                    IntErrorKind::PosOverflow => {
+                        format!("`{}` calories do not fit in 64 bits", entry)
+                    }
+                    _ => format!("expected calories, found `{}`", entry),
+                })
+                .and_then(|entry| {
+                    let total = calories
+                        .checked_add(entry)
+                        .ok_or_else(|| "the total calories do not fit in 64 bits".to_owned())?;
+                    Ok((entry, total))
+                });
+
+            match (total, self.mode) {
+                (Ok((entry, total)), _) => {
+                    items.push(entry);
+                    calories = total;
+                }
+                (Err(reason), Mode::Lenient) => {
+                    warn!(
+                        elf = number,
+                        line = line_number,
+                        text = line,
+                        "skipped: {}",
+                        reason
+                    )
+                }
+                (Err(reason), Mode::Strict) => {
+                    let e = AocError::parse(format!("elf {}: {}", number, reason));
+                    return Some(Err(e
+                        .at_line(line_number, line)
+                        .at_offset(column_of(line, entry))));
+                }
+            }
+        }
+        Some(Ok(Elf {
+            number,
+            calories,
+            items,
+        }))
+    }
+}
+
+pub struct Day1;
+
+#[aoc(day = 1)]
+impl Solution for Day1 {
+    type Input<'a> = Vec<Elf>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> AocResult<Self::Input<'_>> {
+        parse_elves(input)
+    }
+
+    fn parse_reader(reader: &mut dyn BufRead) -> Option<AocResult<Self::Input<'static>>> {
+        Some(read_elves(reader))
+    }
+
+    fn part1(elves: &Self::Input<'_>) -> AocResult<Self::Part1> {
+        most_calories(elves)
+    }
+
+    fn part2(elves: &Self::Input<'_>) -> AocResult<Self::Part2> {
+        top_three_calories(elves)
+    }
+}
+
+fn parse_elves(input: &str) -> AocResult<Vec<Elf>> {
+    parse_elves_with(input, Mode::Strict)
+}
+
+fn read_elves(reader: &mut dyn BufRead) -> AocResult<Vec<Elf>> {
+    read_elves_with(reader, Mode::Strict)
+}
+
+pub fn parse_elves_with(input: &str, mode: Mode) -> AocResult<Vec<Elf>> {
+    ElfMaker::from_lines(input.lines(), mode).collect()
+}
+
+pub fn read_elves_with(reader: &mut dyn BufRead, mode: Mode) -> AocResult<Vec<Elf>> {
+    let mut lines = InputLines::new(reader);
+    let elves = ElfMaker::from_lines(&mut lines, mode).collect::<AocResult<_>>();
+    // A read error ends the lines early, which is the real problem.
+    lines.finish()?;
+    elves
+}
+
+/// Hand each elf to `f` as soon as it is read, keeping none of them.
+pub fn for_each_elf<F>(reader: &mut dyn BufRead, mode: Mode, mut f: F) -> AocResult<()>
+where
+    F: FnMut(Elf) -> AocResult<()>,
+{
+    let mut lines = InputLines::new(reader);
+    let done = ElfMaker::from_lines(&mut lines, mode).try_for_each(|elf| f(elf?));
+    lines.finish()?;
+    done
+}
+
+/// The best `n` of `elves` (or references to them), best first by `Elf`'s
+/// ordering. Only `n` are kept at a time, so they can stream from a reader.
+pub fn top_n<E: Ord>(elves: impl IntoIterator<Item = E>, n: usize) -> Vec<E> {
+    // The worst of the best so far sits on top, ready to be pushed out.
+    let mut best = BinaryHeap::new();
+    for elf in elves {
+        best.push(Reverse(elf));
+        if best.len() > n {
+            best.pop();
+        }
+    }
+    best.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(elf)| elf)
+        .collect()
+}
+
+/// `top_n` straight from a reader, without keeping the other elves.
+pub fn read_top_n(reader: &mut dyn BufRead, n: usize, mode: Mode) -> AocResult<Vec<Elf>> {
+    let mut lines = InputLines::new(reader);
+    let mut error = None;
+    let elves = ElfMaker::from_lines(&mut lines, mode)
+        .map_while(|elf| elf.map_err(|e| error = Some(e)).ok());
+    let best = top_n(elves, n);
+    lines.finish()?;
+    match error {
+        Some(e) => Err(e),
+        None => Ok(best),
+    }
+}
+
+fn most_calories(elves: &[Elf]) -> AocResult<u64> {
+    max_elf(elves)
+        .map(|e| e.calories)
+        .ok_or_else(|| AocError::solve("there are no elves"))
+}
+
+fn top_three_calories(elves: &[Elf]) -> AocResult<u64> {
+    max_three_elves(elves)
+        .ok_or_else(|| AocError::solve("there are fewer than three elves"))?
+        .into_iter()
+        .try_fold(0u64, u64::checked_add)
+        .ok_or_else(|| AocError::solve("the top three totals do not fit in 64 bits"))
+}
+
+fn max_elf(elves: &[Elf]) -> Option<&Elf> {
+    top_n(elves, 1).pop()
+}
+
+fn max_three_elves(elves: &[Elf]) -> Option<[u64; 3]> {
+    let [first, second, third]: [&Elf; 3] = top_n(elves, 3).try_into().ok()?;
+    Some([first, second, third].map(|elf| elf.calories))
+}
+
+#[test]
+fn fake_one_elf_test() {
+    let lines = vec!["1", "2", "3", ""];
+    let em = ElfMaker::new(&lines);
+
+    let binding = em.collect::<AocResult<Vec<_>>>().unwrap();
+    let elf = binding.first().expect("We expect there to be a first elf");
+
+    assert_eq!(elf.number, 0);
+    assert_eq!(elf.calories, 6, "First elf should have 6 calories");
+}
+
+#[test]
+fn fake_multi_elf_test() {
+    // No blank line after the last elf.
+    let lines = vec!["1", "2", "3", "", "2", "2", "2", "2", "", "3", "", "4", "4"];
+    let em = ElfMaker::new(&lines);
+
+    let binding = em.collect::<AocResult<Vec<_>>>().unwrap();
+    assert_eq!(binding.len(), 4);
+    let elf1 = binding.first().unwrap();
+
+    assert_eq!(elf1.number, 0);
+    assert_eq!(elf1.calories, 6);
+
+    let elf2 = binding.get(1).unwrap();
+    assert_eq!(elf2.number, 1);
+    assert_eq!(elf2.calories, 8);
+
+    let elf3 = binding.get(2).unwrap();
+    assert_eq!(elf3.number, 2);
+    assert_eq!(elf3.calories, 3);
+
+    let elf4 = binding.get(3).unwrap();
+    assert_eq!(elf4.number, 3);
+    assert_eq!(elf4.calories, 8);
+}
+
+#[test]
+fn file_read_test() {
+    let input = Input::read("./testinput.txt");
+    match input {
+        Ok(input) => {
+            assert_eq!(input.lines().count(), 12, "line count");
+        }
+        Err(_) => {
+            assert!(input.is_ok())
+        }
+    }
+}
+
+#[test]
+fn elf_counts() {
+    let input = Input::read("./testinput.txt").unwrap();
+    let lines: Vec<_> = input.lines().collect();
+    let em = ElfMaker::new(&lines);
+
+    let elves: Vec<Elf> = em.collect::<AocResult<_>>().unwrap();
+    println!("{:?}", elves);
+
+    assert_eq!(elves.len(), 4);
+}
+
+#[test]
+fn file_max_elf_test() {
+    let elves = parse_elves(&read_input("./testinput.txt").unwrap()).unwrap();
+    let max = max_elf(&elves).unwrap();
+    assert_eq!(max.number, 2, "number");
+    assert_eq!(max.calories, 9, "calories");
+}
+
+#[test]
+fn file_max_three_test() {
+    let elves = parse_elves(&read_input("./testinput.txt").unwrap()).unwrap();
+    let max3 = max_three_elves(&elves).unwrap();
+    assert_eq!(max3, [9, 4, 4]);
+    assert_eq!(top_three_calories(&elves), Ok(17), "top3");
+}
+
+#[test]
+fn last_elf_without_blank_line_test() {
+    let elves = parse_elves("1\n2\n\n3").unwrap();
+    assert_eq!(elves.len(), 2);
+    assert_eq!(elves[1].calories, 3);
+}
+
+#[test]
+fn blank_lines_test() {
+    let lines = vec!["", "1", "2", "", "", " ", "3 ", "\t", "4"];
+    let elves: Vec<_> = ElfMaker::new(&lines).collect::<AocResult<_>>().unwrap();
+    assert_eq!(
+        elves
+            .iter()
+            .map(|e| (e.number, e.calories))
+            .collect::<Vec<_>>(),
+        [(0, 3), (1, 3), (2, 4)]
+    );
+
+    let input = "1\r\n2\r\n\r\n\r\n3  \r\n";
+    assert_eq!(parse_elves(input).unwrap().len(), 2);
+    assert_eq!(
+        read_elves(&mut input.as_bytes()).unwrap(),
+        parse_elves(input).unwrap()
+    );
+    assert!(parse_elves("\n\n").unwrap().is_empty());
+}
+
+#[test]
+fn parse_reader_test() {
+    let input = std::fs::read_to_string("./testinput.txt").unwrap();
+    let streamed = read_elves(&mut input.as_bytes()).unwrap();
+    assert_eq!(streamed, parse_elves(&input).unwrap());
+    assert_eq!(streamed.len(), 4);
+}
+
+#[test]
+fn write_elves_test() {
+    let elves = parse_elves(&read_input("./testinput.txt").unwrap()).unwrap();
+    let written = write_elves(&elves);
+    assert_eq!(written, "1\n1\n\n2\n2\n\n3\n3\n3\n\n4\n");
+    assert_eq!(parse_elves(&written).unwrap(), elves);
+    assert_eq!(
+        elves.iter().map(|e| e.number).collect::<Vec<_>>(),
+        parse_elves(&written)
+            .unwrap()
+            .iter()
+            .map(|e| e.number)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(write_elves(&[]), "");
+}
+
+#[test]
+fn strict_errors_test() {
+    let e = parse_elves("1\n\n2\n  x3\n").unwrap_err();
+    assert_eq!(e.message, "elf 1: expected calories, found `x3`");
+    assert_eq!((e.line, e.column), (Some(4), Some(3)));
+    assert_eq!(e.text.as_deref(), Some("  x3"));
+    assert_eq!(read_elves(&mut "1\n\n2\n  x3\n".as_bytes()), Err(e));
+
+    let e = parse_elves("99999999999999999999\n").unwrap_err();
+    assert_eq!(
+        e.message,
+        "elf 0: `99999999999999999999` calories do not fit in 64 bits"
+    );
+
+    let e = parse_elves("1\n18446744073709551615\n").unwrap_err();
+    assert_eq!(e.message, "elf 0: the total calories do not fit in 64 bits");
+    assert_eq!(e.line, Some(2));
+}
+
+#[test]
+fn lenient_test() {
+    let input = "1\n-2\n3\n\n18446744073709551615\n1\n";
+    let elves = parse_elves_with(input, Mode::Lenient).unwrap();
+    assert_eq!(
+        elves.iter().map(|e| e.calories).collect::<Vec<_>>(),
+        [4, u64::MAX]
+    );
+    assert_eq!(
+        read_elves_with(&mut input.as_bytes(), Mode::Lenient).unwrap(),
+        elves
+    );
+    assert!(parse_elves(input).is_err());
+}
+
+#[test]
+fn big_totals_test() {
+    let input = "4294967295\n1\n\n1\n\n1\n";
+    let elves = parse_elves(input).unwrap();
+    assert_eq!(most_calories(&elves), Ok(4294967296));
+    assert_eq!(top_three_calories(&elves), Ok(4294967298));
+
+    let elves = parse_elves("18446744073709551615\n\n1\n\n1\n").unwrap();
+    assert!(top_three_calories(&elves).is_err());
+}
+
+#[test]
+fn top_n_test() {
+    let elves = parse_elves(&read_input("./testinput.txt").unwrap()).unwrap();
+    let ranked = |top: Vec<&Elf>| {
+        top.iter()
+            .map(|e| (e.number, e.calories))
+            .collect::<Vec<_>>()
+    };
+    // Elves 1 and 3 both carry 4; the earlier one ranks higher.
+    assert_eq!(ranked(top_n(&elves, 3)), [(2, 9), (1, 4), (3, 4)]);
+    assert_eq!(ranked(top_n(&elves, 10)), [(2, 9), (1, 4), (3, 4), (0, 2)]);
+    assert!(top_n(&elves, 0).is_empty());
+    // `n` is only a limit, never an allocation.
+    assert_eq!(top_n(&elves, usize::MAX).len(), 4);
+
+    let input = std::fs::read_to_string("./testinput.txt").unwrap();
+    let streamed = read_top_n(&mut input.as_bytes(), 2, Mode::Strict).unwrap();
+    assert_eq!(streamed, top_n(elves, 2));
+    assert!(read_top_n(&mut "1\n\nx\n".as_bytes(), 2, Mode::Strict).is_err());
+}
+
+#[test]
+fn items_test() {
+    let elves = parse_elves("1000\n2000\n\n7000\n\n4000\n3000\n500\n\n7000\n").unwrap();
+    assert_eq!(elves[2].items, [4000, 3000, 500]);
+    assert_eq!(elves[2].calories, 7500);
+    assert_eq!(
+        elves.iter().map(Elf::item_count).collect::<Vec<_>>(),
+        [2, 1, 3, 1]
+    );
+    assert_eq!(elves[0].breakdown(), "elf 0: 1000 + 2000 = 3000");
+
+    let (elf, item) = heaviest_item(&elves).unwrap();
+    assert_eq!((elf.number, item), (1, 7000));
+    let numbers: Vec<_> = carrying_more_than(&elves, 1).map(|e| e.number).collect();
+    assert_eq!(numbers, [0, 2]);
+    assert_eq!(carrying_more_than(&elves, 3).count(), 0);
+
+    let elves = parse_elves_with("5\nx\n6\n\nx\n", Mode::Lenient).unwrap();
+    assert_eq!(elves[0].items, [5, 6]);
+    assert_eq!(elves[1].breakdown(), "elf 1: nothing = 0");
+    assert_eq!(heaviest_item(&elves[1..]), None);
+}
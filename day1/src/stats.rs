@@ -0,0 +1,325 @@
+//! How the elves' totals are spread: the numbers behind `aoc stats`, an
+//! ASCII histogram, and each elf as a CSV or JSON row.
+
+use std::{
+    fmt::{self, Display},
+    io::Write,
+};
+
+use aoc_common::AocResult;
+
+use crate::Elf;
+
+/// The percentiles the report lists, besides the median.
+const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+/// The widest a histogram bar gets, in characters.
+const BAR_WIDTH: usize = 40;
+
+/// Collects what the report needs as the elves stream past: their totals,
+/// but not their items.
+#[derive(Debug, Clone, Default)]
+pub struct Summary {
+    totals: Vec<u64>,
+}
+
+impl Summary {
+    pub fn add(&mut self, elf: &Elf) {
+        self.totals.push(elf.calories);
+    }
+
+    /// The report over every elf added, unless there were none.
+    pub fn report(mut self, bins: usize) -> Option<Report> {
+        if self.totals.is_empty() {
+            return None;
+        }
+        self.totals.sort_unstable();
+        let totals = &self.totals;
+
+        let count = totals.len() as f64;
+        let mean = totals.iter().map(|&total| total as f64).sum::<f64>() / count;
+        let variance = totals
+            .iter()
+            .map(|&total| (total as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count;
+
+        Some(Report {
+            elves: totals.len(),
+            mean,
+            median: percentile(totals, 50),
+            std_dev: variance.sqrt(),
+            min: totals[0],
+            max: totals[totals.len() - 1],
+            percentiles: PERCENTILES.map(|p| (p, percentile(totals, p))),
+            histogram: histogram(totals, bins),
+        })
+    }
+}
+
+/// The `p`th percentile of sorted, non-empty `totals`, interpolating between
+/// the two nearest when it falls between them.
+fn percentile(totals: &[u64], p: u8) -> f64 {
+    let rank = f64::from(p) / 100.0 * (totals.len() - 1) as f64;
+    let (below, above) = (rank.floor() as usize, rank.ceil() as usize);
+    let fraction = rank - below as f64;
+    totals[below] as f64 + (totals[above] as f64 - totals[below] as f64) * fraction
+}
+
+/// One bar of the histogram: the totals from `low` up to and including
+/// `high`, and how many elves carry them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bin {
+    pub low: u64,
+    pub high: u64,
+    pub elves: usize,
+}
+
+/// `bins` ranges, as near equal as whole numbers allow, from the smallest of
+/// sorted `totals` to the largest (fewer, if there are not that many values
+/// between the two), empty ones included.
+fn histogram(totals: &[u64], bins: usize) -> Vec<Bin> {
+    let (min, max) = (totals[0], totals[totals.len() - 1]);
+    // In `u128`, as `0..=u64::MAX` spans one more than `u64` holds.
+    let span = u128::from(max - min) + 1;
+    let bins = (bins.max(1) as u128).min(span);
+    // Bin `i` holds the totals `t` with `(t - min) * bins / span == i`.
+    let start = |i: u128| min + (i * span).div_ceil(bins) as u64;
+
+    let mut histogram: Vec<Bin> = (0..bins)
+        .map(|i| Bin {
+            low: start(i),
+            high: if i + 1 == bins { max } else { start(i + 1) - 1 },
+            elves: 0,
+        })
+        .collect();
+    for &total in totals {
+        histogram[(u128::from(total - min) * bins / span) as usize].elves += 1;
+    }
+    histogram
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub elves: usize,
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+    pub min: u64,
+    pub max: u64,
+    pub percentiles: [(u8, f64); PERCENTILES.len()],
+    /// Every bin, lowest first, including those no elf falls in.
+    pub histogram: Vec<Bin>,
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "elves    {}", self.elves)?;
+        writeln!(f, "mean     {:.1}", self.mean)?;
+        writeln!(f, "median   {:.1}", self.median)?;
+        writeln!(f, "std dev  {:.1}", self.std_dev)?;
+        writeln!(f, "min      {}", self.min)?;
+        writeln!(f, "max      {}", self.max)?;
+        for (p, value) in self.percentiles {
+            writeln!(f, "{:<8} {:.1}", format!("p{}", p), value)?;
+        }
+
+        writeln!(f)?;
+        let ranges: Vec<String> = self
+            .histogram
+            .iter()
+            .map(|bin| format!("{}-{}", bin.low, bin.high))
+            .collect();
+        let range_width = ranges.iter().map(String::len).max().unwrap_or(0);
+        let most = self
+            .histogram
+            .iter()
+            .map(|bin| bin.elves)
+            .max()
+            .unwrap_or(0);
+        for (range, bin) in ranges.iter().zip(&self.histogram) {
+            // Any bin with elves in it gets at least one mark, and an empty
+            // one none.
+            let bar = (bin.elves * BAR_WIDTH).div_ceil(most);
+            writeln!(
+                f,
+                "{:>width$} | {} {}",
+                range,
+                "#".repeat(bar),
+                bin.elves,
+                width = range_width
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// How `RowWriter` lays out each elf.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// `elf,items,heaviest,calories`, with the number of items.
+    Csv,
+    /// An array of `{"elf": 0, "items": [1000, 2000], "calories": 3000}`.
+    Json,
+}
+
+/// Writes a row per elf as they stream past; `finish` closes the output off.
+pub struct RowWriter<W> {
+    out: W,
+    format: Format,
+    rows: usize,
+}
+
+impl<W: Write> RowWriter<W> {
+    pub fn new(mut out: W, format: Format) -> AocResult<Self> {
+        match format {
+            Format::Csv => writeln!(out, "elf,items,heaviest,calories")?,
+            Format::Json => write!(out, "[")?,
+        }
+        Ok(RowWriter {
+            out,
+            format,
+            rows: 0,
+        })
+    }
+
+    pub fn write(&mut self, elf: &Elf) -> AocResult<()> {
+        match self.format {
+            Format::Csv => writeln!(
+                self.out,
+                "{},{},{},{}",
+                elf.number,
+                elf.item_count(),
+                elf.heaviest_item().unwrap_or(0),
+                elf.calories
+            )?,
+            Format::Json => {
+                let items: Vec<String> = elf.items.iter().map(u64::to_string).collect();
+                let separator = if self.rows == 0 { "" } else { "," };
+                write!(
+                    self.out,
+                    "{}\n  {{\"elf\": {}, \"items\": [{}], \"calories\": {}}}",
+                    separator,
+                    elf.number,
+                    items.join(", "),
+                    elf.calories
+                )?
+            }
+        }
+        self.rows += 1;
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> AocResult<W> {
+        if self.format == Format::Json {
+            let end = if self.rows == 0 { "]" } else { "\n]" };
+            writeln!(self.out, "{}", end)?;
+        }
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+#[cfg(test)]
+use crate::{parse_elves_with, Mode};
+
+#[test]
+fn report_test() {
+    let mut summary = Summary::default();
+    for elf in parse_elves_with("1\n1\n\n2\n2\n\n3\n3\n3\n\n4\n", Mode::Strict).unwrap() {
+        summary.add(&elf);
+    }
+    let report = summary.report(3).unwrap();
+    assert_eq!(report.elves, 4);
+    assert_eq!(report.mean, 4.75);
+    assert_eq!(report.median, 4.0);
+    assert!((report.std_dev - 2.5860).abs() < 1e-4);
+    assert_eq!((report.min, report.max), (2, 9));
+    assert_eq!(report.percentiles[1], (25, 3.5));
+    assert_eq!(
+        report.histogram,
+        [
+            Bin {
+                low: 2,
+                high: 4,
+                elves: 3
+            },
+            Bin {
+                low: 5,
+                high: 7,
+                elves: 0
+            },
+            Bin {
+                low: 8,
+                high: 9,
+                elves: 1
+            },
+        ]
+    );
+
+    let text = report.to_string();
+    assert!(text.starts_with("elves    4\nmean     4.8\nmedian   4.0\n"));
+    assert!(text.contains("\np25      3.5\n"));
+    assert!(text.ends_with(&format!(
+        "\n2-4 | {} 3\n5-7 |  0\n8-9 | {} 1\n",
+        "#".repeat(40),
+        "#".repeat(14)
+    )));
+
+    assert_eq!(Summary::default().report(10), None);
+}
+
+#[test]
+fn histogram_test() {
+    assert_eq!(
+        histogram(&[5, 5], 10),
+        [Bin {
+            low: 5,
+            high: 5,
+            elves: 2
+        }]
+    );
+    let bins = histogram(&[0, u64::MAX], 2);
+    assert_eq!((bins[1].low, bins[1].high), (1 << 63, u64::MAX));
+    assert_eq!(histogram(&[1, 2, 3], 0).len(), 1);
+    assert_eq!(
+        histogram(&[0, u64::MAX], 1),
+        [Bin {
+            low: 0,
+            high: u64::MAX,
+            elves: 2
+        }]
+    );
+    let bins = histogram(&[0, 3, 9], 4);
+    let ranges: Vec<_> = bins
+        .iter()
+        .map(|bin| (bin.low, bin.high, bin.elves))
+        .collect();
+    assert_eq!(ranges, [(0, 2, 1), (3, 4, 1), (5, 7, 0), (8, 9, 1)]);
+}
+
+#[test]
+fn row_writer_test() {
+    let elves = parse_elves_with("1000\n2000\n\n500\n", Mode::Strict).unwrap();
+
+    let mut csv = RowWriter::new(vec![], Format::Csv).unwrap();
+    let mut json = RowWriter::new(vec![], Format::Json).unwrap();
+    for elf in &elves {
+        csv.write(elf).unwrap();
+        json.write(elf).unwrap();
+    }
+    assert_eq!(
+        String::from_utf8(csv.finish().unwrap()).unwrap(),
+        "elf,items,heaviest,calories\n0,2,2000,3000\n1,1,500,500\n"
+    );
+    assert_eq!(
+        String::from_utf8(json.finish().unwrap()).unwrap(),
+        "[\n  {\"elf\": 0, \"items\": [1000, 2000], \"calories\": 3000},\n  {\"elf\": 1, \"items\": [500], \"calories\": 500}\n]\n"
+    );
+
+    let empty = RowWriter::new(vec![], Format::Json)
+        .unwrap()
+        .finish()
+        .unwrap();
+    assert_eq!(String::from_utf8(empty).unwrap(), "[]\n");
+}
@@ -0,0 +1,59 @@
+use aoc_common::{Generated, Rng};
+use aoc_macros::aoc_generator;
+
+#[cfg(test)]
+use crate::{parse_structure_definitions, sand_before_abyss, sand_before_blocked};
+
+/// `size` rock formations scattered below the source, in a cave that gets
+/// deeper (and so holds more sand) as `size` grows: about as deep as the
+/// real input at 150, and deeper than the source is from x = 0 from about
+/// 1500. There is no shortcut to how much sand they hold, so no answers.
+#[aoc_generator(day = 14)]
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let depth = 10 + size.isqrt() as u64 * 13;
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut x = rng.range(500u64.saturating_sub(depth / 2)..=500 + depth / 2);
+        let mut y = rng.range(1..=depth);
+        let mut points = vec![format!("{},{}", x, y)];
+        let mut across = rng.chance(1, 2);
+        for _ in 0..rng.range(1..=4) {
+            let length = rng.range(1..=8);
+            // Head away from the edge if there is no room for the whole line.
+            if across {
+                x = if x > length && rng.chance(1, 2) {
+                    x - length
+                } else {
+                    x + length
+                };
+            } else {
+                y = if y > length && rng.chance(1, 2) {
+                    y - length
+                } else {
+                    y + length
+                };
+            }
+            points.push(format!("{},{}", x, y));
+            across = !across;
+        }
+        input.push_str(&points.join(" -> "));
+        input.push('\n');
+    }
+
+    Generated {
+        input,
+        answers: None,
+    }
+}
+
+#[test]
+fn generate_test() {
+    // At 1500 the pile on the floor spreads past x = 0.
+    for (seed, size) in [(0, 1), (1, 150), (2, 1500)] {
+        let generated = generate(&mut Rng::new(seed), size);
+        assert_eq!(generated.answers, None);
+        let spec = parse_structure_definitions(&generated.input).unwrap();
+        sand_before_abyss(&spec);
+        sand_before_blocked(&spec);
+    }
+}
@@ -0,0 +1,298 @@
+mod generate;
+mod map;
+mod matrix;
+mod point;
+
+use aoc_common::{
+    parse::{parse_lines, parse_pair, separated},
+    AocError, AocResult, Solution,
+};
+use aoc_macros::aoc;
+use map::*;
+use point::*;
+use tracing::{debug, info};
+use SandState::*;
+
+#[cfg(test)]
+use aoc_common::read_input;
+
+/// Where the sand comes in, as the scan numbers it.
+const SOURCE: Point = Point(500, 0);
+
+enum SandState {
+    Moved(Point),
+    Stopped(Point),
+    Escaped,
+}
+
+/// What happens to sand that falls past the lowest rock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Floor {
+    /// It falls forever, which ends the game.
+    Abyss,
+    /// It lands on the floor two rows below the lowest rock.
+    Solid,
+}
+
+pub struct Day14;
+
+#[aoc(day = 14)]
+impl Solution for Day14 {
+    type Input<'a> = MapSpec;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> AocResult<Self::Input<'_>> {
+        parse_structure_definitions(input)
+    }
+
+    fn part1(spec: &Self::Input<'_>) -> AocResult<Self::Part1> {
+        Ok(sand_before_abyss(spec))
+    }
+
+    fn part2(spec: &Self::Input<'_>) -> AocResult<Self::Part2> {
+        Ok(sand_before_blocked(spec))
+    }
+}
+
+fn sand_before_abyss(spec: &MapSpec) -> usize {
+    play_sand_game(spec.clone().into(), Floor::Abyss).0
+}
+
+fn sand_before_blocked(spec: &MapSpec) -> usize {
+    play_sand_game(spec.clone().into(), Floor::Solid).0
+}
+
+fn parse_structure_definitions(input: &str) -> AocResult<MapSpec> {
+    let mut max_x = 0;
+    let mut max_y = 0;
+
+    let rock_formations = parse_lines(input, |line| {
+        let points = separated(line, " -> ", |pair| {
+            let (x, y) = parse_pair::<usize>(pair, ',')?;
+            max_x = max_x.max(x);
+            max_y = max_y.max(y);
+
+            Ok(Point(x, y))
+        })?;
+
+        if points.len() < 2 {
+            return Err(AocError::parse(
+                "a rock formation needs at least two points",
+            ));
+        }
+        Ok(RockFormation(points))
+    })?;
+
+    // The pile can never spread further than its height either side of the
+    // source, so a cave deeper than the source is from x = 0 is moved right.
+    let height = max_y + 3;
+    let offset = height.saturating_sub(SOURCE.0);
+    let width = offset + (max_x + 1).max(SOURCE.0 + height + 1);
+
+    Ok(MapSpec {
+        width,
+        height,
+        offset,
+        rock_formations,
+    })
+}
+
+fn tick(position: Point, map: &Map) -> SandState {
+    let potenial_moves = [
+        position.straight_down(),
+        position.down_left(),
+        position.down_right(),
+    ];
+
+    for the_move in potenial_moves {
+        if the_move.1 >= map.data.height {
+            return Escaped;
+        } else if let MapCell::Air = map[&the_move] {
+            return Moved(the_move);
+        }
+    }
+
+    Stopped(position)
+}
+fn play_sand_game(mut map: Map, floor: Floor) -> (usize, Map) {
+    let mut counter = 0;
+    loop {
+        // New little grain of sand!
+        let source = map.map_spec.source();
+        let mut position = source;
+        counter += 1;
+
+        loop {
+            match tick(position, &map) {
+                Moved(pos) => {
+                    position = pos;
+                }
+                Stopped(position) => {
+                    // Only the floor is below the lowest rock, so without it this grain would
+                    // have kept falling.
+                    if floor == Floor::Abyss && position.1 + 2 == map.data.height {
+                        info!(grains = counter - 1, ?floor, "sand falls into the abyss");
+                        return (counter - 1, map);
+                    }
+
+                    map[position] = MapCell::Sand;
+                    debug!(
+                        grain = counter,
+                        x = position.0,
+                        y = position.1,
+                        "grain came to rest"
+                    );
+                    if position == source {
+                        info!(grains = counter, ?floor, "sand blocks the source");
+                        return (counter, map);
+                    }
+                    break;
+                }
+                Escaped => {
+                    info!(grains = counter - 1, ?floor, "sand falls off the map");
+                    return (counter - 1, map);
+                }
+            }
+        }
+    }
+}
+#[test]
+fn test_map_parse_from_spec() {
+    let _map: Map = parse_structure_definitions(&read_input("./test.txt").unwrap())
+        .expect("file should be readable")
+        .into();
+}
+
+#[test]
+fn parse_file_structures_test() {
+    let MapSpec {
+        height,
+        rock_formations,
+        ..
+    } = parse_structure_definitions(&read_input("./test.txt").unwrap())
+        .expect("file should be readable");
+
+    // assert_eq!(width, 1006);
+    assert_eq!(height, 12);
+    assert_eq!(rock_formations.len(), 2);
+    assert_eq!(
+        rock_formations[0].0,
+        vec![Point(498, 4), Point(498, 6), Point(496, 6)]
+    );
+
+    assert_eq!(
+        rock_formations[1].0,
+        vec![Point(503, 4), Point(502, 4), Point(502, 9), Point(494, 9)]
+    );
+}
+
+#[test]
+fn write_spec_test() {
+    let input = std::fs::read_to_string("./test.txt").unwrap();
+    let spec = parse_structure_definitions(&input).unwrap();
+    assert_eq!(spec.to_string(), input.trim_end().to_owned() + "\n");
+    assert_eq!(
+        parse_structure_definitions(&spec.to_string()).unwrap(),
+        spec
+    );
+
+    // A spec with a formation taken away still writes out as a valid input.
+    let mut spec = spec;
+    spec.rock_formations.pop();
+    assert_eq!(spec.to_string(), "498,4 -> 498,6 -> 496,6\n");
+    assert_eq!(
+        parse_structure_definitions(&spec.to_string())
+            .unwrap()
+            .rock_formations,
+        spec.rock_formations
+    );
+}
+
+#[test]
+fn play_sand_game_test() {
+    let spec = parse_structure_definitions(&read_input("./test.txt").unwrap()).unwrap();
+    assert_eq!(play_sand_game(spec.clone().into(), Floor::Abyss).0, 24);
+    assert_eq!(play_sand_game(spec.into(), Floor::Solid).0, 93);
+}
+
+#[test]
+fn deep_cave_test() {
+    // Deep enough that the pile on the floor spreads left of x = 0.
+    let spec = parse_structure_definitions("1,500 -> 2,500\n").unwrap();
+    assert_eq!(spec.offset, 3);
+    assert_eq!(sand_before_abyss(&spec), 0);
+    // Every cell down to the floor but the two rocks.
+    assert_eq!(sand_before_blocked(&spec), 502 * 502 - 2);
+}
+
+#[test]
+fn parse_error_test() {
+    let e = parse_structure_definitions("498,4 -> 498,6\n503,4 -> 502;4")
+        .err()
+        .unwrap();
+    assert_eq!((e.line, e.column), (Some(2), Some(10)));
+
+    assert!(parse_structure_definitions("498,4").is_err());
+}
+
+#[test]
+fn point_ordering_test() {
+    let one = Point(100, 1);
+    let two = Point(100, 2);
+
+    assert!(one < two);
+    assert!(two > one);
+
+    let one = Point(100, 0);
+    let two = Point(101, 0);
+
+    assert!(one < two);
+    assert!(two > one);
+
+    assert_eq!(one.max(two), two);
+}
+
+#[test]
+fn line_generation_test() {
+    /* 498,4 -> 498,6 -> 496,6 */
+    assert_eq!(
+        Point::line_expand(&Point(498, 4)..&Point(498, 6)).collect::<Vec<_>>(),
+        vec![Point(498, 4), Point(498, 5), Point(498, 6)]
+    );
+
+    assert_eq!(
+        Point::line_expand(&Point(498, 6)..&Point(496, 6)).collect::<Vec<_>>(),
+        vec![Point(496, 6), Point(497, 6), Point(498, 6)]
+    );
+    // 503,4 -> 502,4 -> 502,9 -> 494,9
+    assert_eq!(
+        Point::line_expand(&Point(503, 4)..&Point(502, 4)).collect::<Vec<_>>(),
+        vec![Point(502, 4), Point(503, 4),]
+    );
+    assert_eq!(
+        Point::line_expand(&Point(502, 4)..&Point(502, 9)).collect::<Vec<_>>(),
+        vec![
+            Point(502, 4),
+            Point(502, 5),
+            Point(502, 6),
+            Point(502, 7),
+            Point(502, 8),
+            Point(502, 9),
+        ]
+    );
+    assert_eq!(
+        Point::line_expand(&Point(502, 9)..&Point(494, 9)).collect::<Vec<_>>(),
+        vec![
+            Point(494, 9),
+            Point(495, 9),
+            Point(496, 9),
+            Point(497, 9),
+            Point(498, 9),
+            Point(499, 9),
+            Point(500, 9),
+            Point(501, 9),
+            Point(502, 9),
+        ]
+    );
+}
@@ -3,9 +3,12 @@ use std::{
     ops::{Index, IndexMut},
 };
 
+use tracing::debug;
+
 use crate::{
     matrix::{Matrix, MatrixIndex, Rectangle},
     point::Point,
+    SOURCE,
 };
 
 impl Display for MapCell {
@@ -23,6 +26,29 @@ impl Display for MapCell {
     }
 }
 
+/// A formation as the scan lists it, `498,4 -> 498,6 -> 496,6`.
+impl Display for RockFormation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, point) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " -> ")?;
+            }
+            write!(f, "{}", point)?;
+        }
+        Ok(())
+    }
+}
+
+/// The scan the spec was parsed from, a formation to a line.
+impl Display for MapSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for formation in &self.rock_formations {
+            writeln!(f, "{}", formation)?;
+        }
+        Ok(())
+    }
+}
+
 impl Display for Rectangle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:?}-{:?}", self.upper_left, self.lower_right)
@@ -41,7 +67,7 @@ impl Display for Matrix<MapCell> {
                 for col in window.upper_left.col..=window.lower_right.col {
                     write!(f, "{}", &self[MatrixIndex { row, col }])?;
                 }
-                write!(f, "\n")?;
+                writeln!(f)?;
             }
         } else {
             writeln!(
@@ -54,35 +80,44 @@ impl Display for Matrix<MapCell> {
                 for i in self.row(row) {
                     write!(f, "{}", i)?;
                 }
-                write!(f, "\n")?;
+                writeln!(f)?;
             }
         }
 
         Ok(())
     }
 }
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RockFormation(pub Vec<Point>);
 
+/// The rock formations, and a map big enough for them, the floor below them
+/// and the sand.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MapSpec {
     pub width: usize,
     pub height: usize,
+    /// How far right of where the scan puts them everything sits on the map,
+    /// so that sand spreading past x = 0 still has room.
+    pub offset: usize,
     pub rock_formations: Vec<RockFormation>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+impl MapSpec {
+    /// Where the sand comes in, on the map.
+    pub fn source(&self) -> Point {
+        Point(SOURCE.0 + self.offset, SOURCE.1)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum MapCell {
+    #[default]
     Air,
     Rock,
     Sand,
     Entrance,
 }
 
-impl Default for MapCell {
-    fn default() -> Self {
-        MapCell::Air
-    }
-}
-
 pub struct Map {
     pub map_spec: MapSpec,
     pub data: Matrix<MapCell>,
@@ -123,20 +158,34 @@ impl IndexMut<Point> for Map {
 
 impl From<MapSpec> for Map {
     fn from(map_spec: MapSpec) -> Self {
+        debug!(
+            height = map_spec.height,
+            width = map_spec.width,
+            formations = map_spec.rock_formations.len(),
+            "building map"
+        );
         let mut m = Map {
-            data: Matrix::new(dbg!(map_spec.height), dbg!(map_spec.width)),
+            data: Matrix::new(map_spec.height, map_spec.width),
             map_spec,
         };
 
-        for RockFormation(formation) in m.map_spec.rock_formations.iter() {
+        let offset = m.map_spec.offset;
+        for RockFormation(formation) in &m.map_spec.rock_formations {
             for window in formation.windows(2) {
                 for p in Point::line_expand(&window[0]..&window[1]) {
-                    m.data[p.into()] = MapCell::Rock;
+                    m.data[Point(p.0 + offset, p.1).into()] = MapCell::Rock;
                 }
             }
         }
+        // The floor runs along the bottom row, under every formation.
+        for col in 0..m.map_spec.width {
+            m.data[MatrixIndex {
+                row: m.map_spec.height - 1,
+                col,
+            }] = MapCell::Rock;
+        }
 
-        m.data[Point(500, 0).into()] = MapCell::Entrance;
+        m.data[m.map_spec.source().into()] = MapCell::Entrance;
 
         m
     }
@@ -41,7 +41,7 @@ where
 {
     pub fn row(&self, row_number: usize) -> &[T] {
         let start_idx = self.width * row_number;
-        let end_idx = &start_idx + self.width;
+        let end_idx = start_idx + self.width;
 
         &self.data[start_idx..end_idx]
     }
@@ -88,19 +88,10 @@ where
 {
     fn index_mut(&mut self, MatrixIndex { row, col }: MatrixIndex) -> &mut Self::Output {
         self.interesting_window = Some(match &self.interesting_window {
-            Some(current_window) => current_window.include(MatrixIndex {
-                row: row,
-                col: col,
-            }),
+            Some(current_window) => current_window.include(MatrixIndex { row, col }),
             None => Rectangle {
-                upper_left: MatrixIndex {
-                    row: row,
-                    col: col,
-                },
-                lower_right: MatrixIndex {
-                    row: row,
-                    col: col,
-                },
+                upper_left: MatrixIndex { row, col },
+                lower_right: MatrixIndex { row, col },
             },
         });
 
@@ -1,8 +1,10 @@
-use std::ops::{Range, Sub};
+use std::{
+    fmt::Display,
+    ops::{Range, Sub},
+};
 
 use crate::matrix::MatrixIndex;
 
-
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Default)]
 pub struct Point(pub usize, pub usize);
 impl Point {
@@ -10,7 +12,7 @@ impl Point {
         let first = range.end.min(range.start);
         let second = range.end.max(range.start);
 
-        (first.0.clone()..=second.0)
+        (first.0..=second.0)
             .flat_map(move |row| (first.1..=second.1).map(move |col| Point(row, col)))
     }
 
@@ -27,6 +29,13 @@ impl Point {
     }
 }
 
+/// `x,y`, as in the scan.
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.0, self.1)
+    }
+}
+
 impl Sub for Point {
     type Output = Self;
 
@@ -39,4 +48,4 @@ impl From<Point> for MatrixIndex {
     fn from(Point(col, row): Point) -> Self {
         MatrixIndex { row, col }
     }
-}
\ No newline at end of file
+}
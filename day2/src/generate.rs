@@ -0,0 +1,30 @@
+use aoc_common::{Generated, Rng};
+use aoc_macros::aoc_generator;
+
+/// `size` rounds of the strategy guide. Shapes and outcomes are numbered 0
+/// to 2: rock, paper, scissors and lose, draw, win.
+#[aoc_generator(day = 2)]
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let (mut part1, mut part2) = (0u64, 0u64);
+    for _ in 0..size {
+        let theirs = rng.range(0..=2);
+        let column = rng.range(0..=2);
+        input.push(['A', 'B', 'C'][theirs as usize]);
+        input.push(' ');
+        input.push(['X', 'Y', 'Z'][column as usize]);
+        input.push('\n');
+
+        // Read as the shape to play: one round ahead of theirs wins.
+        let outcome = (column + 4 - theirs) % 3;
+        part1 += column + 1 + 3 * outcome;
+        // Read as the outcome to get.
+        let shape = (theirs + column + 2) % 3;
+        part2 += shape + 1 + 3 * column;
+    }
+
+    Generated {
+        input,
+        answers: Some([part1.to_string(), part2.to_string()]),
+    }
+}
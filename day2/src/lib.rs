@@ -0,0 +1,317 @@
+mod generate;
+
+use std::{cmp::Ordering, io::BufRead, str::FromStr};
+
+use aoc_common::{
+    parse::{column_of, parse_lines, parse_lines_from, tokens},
+    AocError, AocResult, Solution,
+};
+use aoc_macros::aoc;
+
+#[cfg(test)]
+use aoc_common::read_input;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum RockPaperScissors {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl TryFrom<char> for RockPaperScissors {
+    type Error = AocError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'A' | 'X' => Ok(Self::Rock),
+            'B' | 'Y' => Ok(Self::Paper),
+            'C' | 'Z' => Ok(Self::Scissors),
+            _ => Err(unknown_move(&c.to_string())),
+        }
+    }
+}
+
+impl FromStr for RockPaperScissors {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        move_letter(s)?.try_into()
+    }
+}
+
+fn unknown_move(s: &str) -> AocError {
+    AocError::parse(format!("unknown move `{}`", s)).with_text(s)
+}
+
+/// The single letter that a column of the strategy guide holds.
+fn move_letter(token: &str) -> AocResult<char> {
+    let mut chars = token.chars();
+    match (chars.next(), chars.next()) {
+        (Some(letter), None) if RockPaperScissors::try_from(letter).is_ok() => Ok(letter),
+        _ => Err(unknown_move(token)),
+    }
+}
+
+impl PartialOrd for RockPaperScissors {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        let loss = Some(std::cmp::Ordering::Less);
+        let win = Some(std::cmp::Ordering::Greater);
+        match (self, other) {
+            (Self::Rock, Self::Paper) => loss,
+            (Self::Paper, Self::Scissors) => loss,
+            (Self::Scissors, Self::Rock) => loss,
+            (Self::Rock, Self::Scissors) => win,
+            (Self::Paper, Self::Rock) => win,
+            (Self::Scissors, Self::Paper) => win,
+            (_, _) => Some(std::cmp::Ordering::Equal),
+        }
+    }
+}
+
+pub struct Day2;
+
+#[aoc(day = 2)]
+impl Solution for Day2 {
+    type Input<'a> = Vec<(char, char)>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> AocResult<Self::Input<'_>> {
+        get_entries(input)
+    }
+
+    fn parse_reader(reader: &mut dyn BufRead) -> Option<AocResult<Self::Input<'static>>> {
+        Some(read_entries(reader))
+    }
+
+    fn part1(game_lines: &Self::Input<'_>) -> AocResult<Self::Part1> {
+        score_tournament_1(game_lines)
+    }
+
+    fn part2(game_lines: &Self::Input<'_>) -> AocResult<Self::Part2> {
+        score_tournament_2(game_lines)
+    }
+}
+
+fn get_entries(content: &str) -> AocResult<Vec<(char, char)>> {
+    parse_lines(content, parse_round)
+}
+
+fn read_entries(reader: &mut dyn BufRead) -> AocResult<Vec<(char, char)>> {
+    parse_lines_from(reader, parse_round)
+}
+
+/// The strategy guide for `rounds`, one round to a line.
+pub fn write_entries(rounds: &[(char, char)]) -> String {
+    rounds
+        .iter()
+        .map(|(theirs, mine)| format!("{} {}\n", theirs, mine))
+        .collect()
+}
+
+fn parse_round(line: &str) -> AocResult<(char, char)> {
+    match tokens(line)[..] {
+        [theirs, mine] => {
+            // Both columns use letters that parse as a move, so check them here where we still
+            // know which line they came from.
+            let letter =
+                |token| move_letter(token).map_err(|e| e.at_offset(column_of(line, token)));
+            Ok((letter(theirs)?, letter(mine)?))
+        }
+        _ => Err(AocError::parse("expected two moves")),
+    }
+}
+
+fn score_tournament_1(game_lines: &[(char, char)]) -> AocResult<u64> {
+    total_score(game_lines.iter().map(|&game| score_game_1(game)))
+}
+
+fn score_tournament_2(game_lines: &[(char, char)]) -> AocResult<u64> {
+    total_score(game_lines.iter().map(|&game| score_game_2(game)))
+}
+
+fn total_score(scores: impl Iterator<Item = AocResult<u64>>) -> AocResult<u64> {
+    let mut total = 0u64;
+    for score in scores {
+        total = total
+            .checked_add(score?)
+            .ok_or_else(|| AocError::solve("the total score does not fit in 64 bits"))?;
+    }
+    Ok(total)
+}
+
+fn score_game_1(scores: (char, char)) -> AocResult<u64> {
+    let (theirs, mine): (RockPaperScissors, RockPaperScissors) =
+        (scores.0.try_into()?, scores.1.try_into()?);
+
+    Ok(get_score(mine, theirs))
+}
+
+fn get_loser(other: &RockPaperScissors) -> RockPaperScissors {
+    match other {
+        RockPaperScissors::Rock => RockPaperScissors::Scissors,
+        RockPaperScissors::Paper => RockPaperScissors::Rock,
+        RockPaperScissors::Scissors => RockPaperScissors::Paper,
+    }
+}
+
+fn get_winner(other: &RockPaperScissors) -> RockPaperScissors {
+    match other {
+        RockPaperScissors::Rock => RockPaperScissors::Paper,
+        RockPaperScissors::Paper => RockPaperScissors::Scissors,
+        RockPaperScissors::Scissors => RockPaperScissors::Rock,
+    }
+}
+
+fn score_game_2(game: (char, char)) -> AocResult<u64> {
+    let theirs: RockPaperScissors = game.0.try_into()?;
+    let mine = match game.1 {
+        'X' => get_loser(&theirs),
+        'Y' => theirs,
+        'Z' => get_winner(&theirs),
+        other => {
+            let other = other.to_string();
+            return Err(AocError::parse(format!("unknown outcome `{}`", other)).with_text(&other));
+        }
+    };
+
+    Ok(get_score(mine, theirs))
+}
+
+fn get_score(mine: RockPaperScissors, theirs: RockPaperScissors) -> u64 {
+    let game_score = match &mine.partial_cmp(&theirs).unwrap() {
+        Ordering::Greater => 6,
+        Ordering::Less => 0,
+        Ordering::Equal => 3,
+    };
+    let my_play_score = match &mine {
+        RockPaperScissors::Rock => 1,
+        RockPaperScissors::Paper => 2,
+        RockPaperScissors::Scissors => 3,
+    };
+
+    game_score + my_play_score
+}
+
+/*
+For example, suppose you were given the following strategy guide:
+
+A Y
+B X
+C Z
+This strategy guide predicts and recommends the following:
+
+In the first round, your opponent will choose Rock (A), and you should choose Paper (Y). This ends in a win for you with a score of 8 (2 because you chose Paper + 6 because you won).
+In the second round, your opponent will choose Paper (B), and you should choose Rock (X). This ends in a loss for you with a score of 1 (1 + 0).
+The third round is a draw with both players choosing Scissors, giving you a score of 3 + 3 = 6.
+In this example, if you were to follow the strategy guide, you would get a total score of 15 (8 + 1 + 6). */
+#[test]
+fn score_game_1_test() {
+    assert_eq!(score_game_1(('A', 'Y')), Ok(8));
+    assert_eq!(score_game_1(('B', 'X')), Ok(1));
+    assert_eq!(score_game_1(('C', 'Z')), Ok(6));
+}
+
+#[test]
+fn score_tournament_1_test() {
+    assert_eq!(
+        score_tournament_1(&get_entries(&read_input("./test2.txt").unwrap()).unwrap()),
+        Ok(15)
+    );
+}
+
+#[test]
+fn score_game_2_test() {
+    assert_eq!(score_game_2(('A', 'Y')), Ok(4));
+    assert_eq!(score_game_2(('B', 'X')), Ok(1));
+    assert_eq!(score_game_2(('C', 'Z')), Ok(7));
+}
+
+#[test]
+fn score_tournament_2_test() {
+    assert_eq!(
+        score_tournament_2(&get_entries(&read_input("./test2.txt").unwrap()).unwrap()),
+        Ok(12)
+    );
+}
+
+#[test]
+fn parse_lines_test() {
+    let line_tuples = get_entries(&read_input("./test.txt").unwrap()).unwrap();
+    assert_eq!(line_tuples.first().unwrap(), &('C', 'X'));
+    assert_eq!(line_tuples.get(1).unwrap(), &('B', 'Y'));
+    assert_eq!(line_tuples.get(2).unwrap(), &('C', 'Z'));
+    assert_eq!(line_tuples.get(3).unwrap(), &('C', 'Z'));
+}
+
+#[test]
+fn rock_paper_scissors_ordering_test() {
+    assert!(RockPaperScissors::Rock > RockPaperScissors::Scissors);
+    assert!(RockPaperScissors::Paper > RockPaperScissors::Rock);
+    assert!(RockPaperScissors::Scissors > RockPaperScissors::Paper);
+}
+
+#[test]
+fn parse_enum_test() {
+    assert_eq!(
+        "A".parse::<RockPaperScissors>(),
+        Ok(RockPaperScissors::Rock)
+    );
+    assert_eq!(
+        "X".parse::<RockPaperScissors>(),
+        Ok(RockPaperScissors::Rock)
+    );
+    assert_eq!(
+        "B".parse::<RockPaperScissors>(),
+        Ok(RockPaperScissors::Paper)
+    );
+    assert_eq!(
+        "Y".parse::<RockPaperScissors>(),
+        Ok(RockPaperScissors::Paper)
+    );
+    assert_eq!(
+        "C".parse::<RockPaperScissors>(),
+        Ok(RockPaperScissors::Scissors)
+    );
+    assert_eq!(
+        "Z".parse::<RockPaperScissors>(),
+        Ok(RockPaperScissors::Scissors)
+    );
+}
+
+#[test]
+fn parse_error_test() {
+    let e = get_entries("A Y\nB Q\n").unwrap_err();
+    assert_eq!(e.line, Some(2));
+    assert_eq!(e.column, Some(3));
+    assert_eq!(e.message, "unknown move `Q`");
+
+    assert_eq!(get_entries("A\n").unwrap_err().line, Some(1));
+}
+
+#[test]
+fn parse_reader_test() {
+    let input = std::fs::read_to_string("./test.txt").unwrap();
+    assert_eq!(read_entries(&mut input.as_bytes()), get_entries(&input));
+
+    let e = read_entries(&mut "A Y\nB Q\n".as_bytes()).unwrap_err();
+    assert_eq!((e.line, e.column), (Some(2), Some(3)));
+}
+
+#[test]
+fn write_entries_test() {
+    let input = std::fs::read_to_string("./test2.txt").unwrap();
+    let rounds = get_entries(&input).unwrap();
+    assert_eq!(write_entries(&rounds), input);
+
+    let rounds = get_entries("A   Z\n").unwrap();
+    assert_eq!(write_entries(&rounds), "A Z\n");
+}
+
+#[test]
+fn total_score_test() {
+    assert_eq!(total_score([Ok(8), Ok(1), Ok(6)].into_iter()), Ok(15));
+    assert!(total_score([Ok(u64::MAX), Ok(1)].into_iter()).is_err());
+    let e = AocError::parse("unknown move `Q`");
+    assert_eq!(total_score([Ok(1), Err(e.clone())].into_iter()), Err(e));
+}
@@ -0,0 +1,47 @@
+use aoc_common::{Generated, Rng};
+use aoc_macros::aoc_generator;
+
+fn priority(item: u8) -> u64 {
+    match item {
+        b'a'..=b'z' => (item - b'a' + 1) as u64,
+        _ => (item - b'A' + 27) as u64,
+    }
+}
+
+/// `size` rucksacks, rounded up to whole groups of three. Each group's
+/// letters are shuffled and dealt out so that the badge is the only one all
+/// three share, and each elf's own letters split so that one item is in both
+/// halves.
+#[aoc_generator(day = 3)]
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut letters: Vec<u8> = (b'a'..=b'z').chain(b'A'..=b'Z').collect();
+    let mut input = String::new();
+    let (mut part1, mut part2) = (0, 0);
+
+    for _ in 0..size.div_ceil(3).max(1) {
+        rng.shuffle(&mut letters);
+        let badge = letters[0];
+        part2 += priority(badge);
+
+        for own in letters[1..].chunks(17) {
+            let shared = own[0];
+            part1 += priority(shared);
+
+            let half = rng.range(2..=9) as usize;
+            let mut left = vec![shared, badge];
+            left.extend((0..half - 2).map(|_| *rng.choose(&own[1..9])));
+            let mut right = vec![shared];
+            right.extend((0..half - 1).map(|_| *rng.choose(&own[9..])));
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+
+            input.extend(left.iter().chain(&right).map(|&item| item as char));
+            input.push('\n');
+        }
+    }
+
+    Generated {
+        input,
+        answers: Some([part1.to_string(), part2.to_string()]),
+    }
+}
@@ -1,12 +1,41 @@
-use std::{
-    collections::HashSet,
-    fs::File,
-    io::{self, BufRead},
-};
-
-fn main() {
-    println!("Priority Sum: {}", score_file("./input.txt"));
-    println!("Badge Score: {}", badge_score_file("./input.txt"));
+mod generate;
+
+use std::collections::HashSet;
+
+use aoc_common::{parse::parse_lines, AocError, AocResult, Solution};
+use aoc_macros::aoc;
+
+#[cfg(test)]
+use aoc_common::read_input;
+
+pub struct Day3;
+
+#[aoc(day = 3)]
+impl Solution for Day3 {
+    type Input<'a> = Vec<&'a str>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> AocResult<Self::Input<'_>> {
+        parse_rucksacks(input)
+    }
+
+    fn part1(rucksacks: &Self::Input<'_>) -> AocResult<Self::Part1> {
+        Ok(score_rucksacks(rucksacks))
+    }
+
+    fn part2(rucksacks: &Self::Input<'_>) -> AocResult<Self::Part2> {
+        get_badge_scores(rucksacks)
+    }
+}
+
+fn parse_rucksacks(input: &str) -> AocResult<Vec<&str>> {
+    parse_lines(input, |line| {
+        match line.chars().position(|c| !c.is_ascii_alphabetic()) {
+            Some(i) => Err(AocError::parse("items must be letters").at_column(i + 1)),
+            None => Ok(line),
+        }
+    })
 }
 
 fn score_line(line: &str) -> i32 {
@@ -18,20 +47,21 @@ fn score_line(line: &str) -> i32 {
     both.into_iter().map(|x| get_priority(*x)).sum()
 }
 
-fn get_badge_scores<T: AsRef<str>>(elves: &[T]) -> i32 {
+fn get_badge_scores(elves: &[&str]) -> AocResult<i32> {
     elves
         .chunks(3)
-        .map(|chunk| {
-            *chunk
+        .enumerate()
+        .map(|(group, chunk)| {
+            chunk
                 .iter()
-                .map(|x| (*x.as_ref()).chars().collect::<HashSet<_>>())
-                .reduce(|acc, next| acc.intersection(&next).into_iter().map(|x| *x).collect())
-                .unwrap()
-                .iter()
-                .next()
-                .unwrap()
+                .map(|x| x.chars().collect::<HashSet<_>>())
+                .reduce(|acc, next| acc.intersection(&next).copied().collect())
+                .and_then(|common| common.into_iter().next())
+                .map(get_priority)
+                .ok_or_else(|| {
+                    AocError::solve(format!("group {} has no badge in common", group + 1))
+                })
         })
-        .map(get_priority)
         .sum()
 }
 
@@ -45,23 +75,8 @@ fn get_priority(c: char) -> i32 {
     }
 }
 
-fn score_file(filename: &str) -> i32 {
-    read_lines(filename)
-        .unwrap()
-        .iter()
-        .map(|x| score_line(x))
-        .sum()
-}
-
-fn badge_score_file(filename: &str) -> i32 {
-    get_badge_scores(&read_lines(filename).unwrap())
-}
-
-fn read_lines(filename: &str) -> Option<Vec<String>> {
-    let file = File::open(filename).ok()?;
-    let lines = io::BufReader::new(file).lines().map(|x| x.unwrap());
-
-    Some(lines.collect::<Vec<_>>())
+fn score_rucksacks(rucksacks: &[&str]) -> i32 {
+    rucksacks.iter().map(|x| score_line(x)).sum()
 }
 
 /*
@@ -111,29 +126,47 @@ fn test_simple_score_line() {
 
 #[test]
 fn test_score_input() {
-    assert_eq!(score_file("./test.txt"), 157)
+    assert_eq!(
+        score_rucksacks(&parse_rucksacks(&read_input("./test.txt").unwrap()).unwrap()),
+        157
+    )
 }
 
 #[test]
 fn test_simple_chunks() {
-    let group1 = vec![
+    let group1 = [
         "vJrwpWtwJgWrhcsFMMfFFhFp",
         "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
         "PmmdzqPrVvPwwTWBwg",
     ];
 
-    assert_eq!(get_badge_scores(&group1), 18);
+    assert_eq!(get_badge_scores(&group1), Ok(18));
 
-    let group2 = vec![
+    let group2 = [
         "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
         "ttgJtRGJQctTZtZT",
         "CrZsJsPPZsGzwwsLwLmpwMDw",
     ];
 
-    assert_eq!(get_badge_scores(&group2), 52)
+    assert_eq!(get_badge_scores(&group2), Ok(52))
 }
 
 #[test]
 fn test_badge_scores() {
-    assert_eq!(badge_score_file("./test.txt"), 70);
+    assert_eq!(
+        get_badge_scores(&parse_rucksacks(&read_input("./test.txt").unwrap()).unwrap()),
+        Ok(70)
+    );
+}
+
+#[test]
+fn missing_badge_test() {
+    let e = get_badge_scores(&["ab", "cd", "ef"]).unwrap_err();
+    assert_eq!(e.message, "group 1 has no badge in common");
+}
+
+#[test]
+fn parse_error_test() {
+    let e = parse_rucksacks("abc\nab1c").unwrap_err();
+    assert_eq!((e.line, e.column), (Some(2), Some(3)));
 }
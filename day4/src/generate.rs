@@ -0,0 +1,50 @@
+use aoc_common::{Generated, Rng};
+use aoc_macros::aoc_generator;
+
+/// `size` pairs of section ranges between 1 and 99, each pair made to be one
+/// of: one range inside the other, overlapping only partly, or apart.
+#[aoc_generator(day = 4)]
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let (mut part1, mut part2) = (0, 0);
+    for _ in 0..size {
+        let (first, second) = match rng.range(0..=2) {
+            0 => {
+                part1 += 1;
+                part2 += 1;
+                let (start, end) = (rng.range(1..=99), rng.range(1..=99));
+                let (start, end) = (start.min(end), start.max(end));
+                let inner_start = rng.range(start..=end);
+                ((start, end), (inner_start, rng.range(inner_start..=end)))
+            }
+            1 => {
+                part2 += 1;
+                // start < other_start <= end < other_end
+                let start = rng.range(1..=96);
+                let other_start = rng.range(start + 1..=97);
+                let end = rng.range(other_start..=98);
+                ((start, end), (other_start, rng.range(end + 1..=99)))
+            }
+            _ => {
+                let start = rng.range(1..=97);
+                let end = rng.range(start..=98);
+                let other_start = rng.range(end + 1..=99);
+                ((start, end), (other_start, rng.range(other_start..=99)))
+            }
+        };
+        let (first, second) = if rng.chance(1, 2) {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        input.push_str(&format!(
+            "{}-{},{}-{}\n",
+            first.0, first.1, second.0, second.1
+        ));
+    }
+
+    Generated {
+        input,
+        answers: Some([part1.to_string(), part2.to_string()]),
+    }
+}
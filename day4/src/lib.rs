@@ -1,17 +1,20 @@
-use itertools::Itertools;
+mod generate;
 
-use crate::aoc_file::{get_file_param, read_lines};
+use std::fmt::Display;
 
-mod aoc_file;
+use aoc_common::{
+    parse,
+    parse::{pair_with, parse_lines},
+    AocError, AocResult, Solution,
+};
+use aoc_macros::aoc;
 
-fn main() {
-    println!("Overlaps: {}", count_containments(&get_file_param()));
-    println!("Intersections: {}", count_intersections(&get_file_param()));
-}
+#[cfg(test)]
+use aoc_common::read_input;
 
 #[derive(Debug, PartialEq, Eq)]
 #[non_exhaustive]
-struct CleaningRange {
+pub struct CleaningRange {
     start: i32,
     end: i32,
 }
@@ -38,52 +41,84 @@ impl CleaningRange {
     }
 }
 
+impl Display for CleaningRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
 impl TryFrom<&str> for CleaningRange {
-    type Error = String;
+    type Error = AocError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let mut values = value.split("-").map(|x| x.parse::<i32>());
-        match (values.next(), values.next()) {
-            (Some(Ok(start)), Some(Ok(end))) => Ok(CleaningRange::new(start, end)),
-            (_, _) => Err(format!("Unable to parse {}", value)),
+        let (start, end) = parse!("{}-{}", value)?;
+        if start > end {
+            return Err(AocError::parse(format!(
+                "range {} ends before it starts",
+                value
+            )));
         }
+        Ok(CleaningRange::new(start, end))
+    }
+}
+
+fn parse_line<T: AsRef<str>>(line: T) -> AocResult<(CleaningRange, CleaningRange)> {
+    pair_with(line.as_ref(), ',', |range| CleaningRange::try_from(range))
+}
+
+/// The assignment list for `pairs`, one pair to a line.
+pub fn write_pairs(pairs: &[(CleaningRange, CleaningRange)]) -> String {
+    pairs
+        .iter()
+        .map(|(first, second)| format!("{},{}\n", first, second))
+        .collect()
+}
+
+pub struct Day4;
+
+#[aoc(day = 4)]
+impl Solution for Day4 {
+    type Input<'a> = Vec<(CleaningRange, CleaningRange)>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> AocResult<Self::Input<'_>> {
+        parse_pairs(input)
+    }
+
+    fn part1(pairs: &Self::Input<'_>) -> AocResult<Self::Part1> {
+        Ok(count_containments(pairs))
+    }
+
+    fn part2(pairs: &Self::Input<'_>) -> AocResult<Self::Part2> {
+        Ok(count_intersections(pairs))
     }
 }
 
-fn parse_line<T: AsRef<str>>(line: T) -> (CleaningRange, CleaningRange) {
-    // Assumes the input is well behaved.
-    line.as_ref()
-        .split(",")
-        .map(|x| CleaningRange::try_from(x).unwrap())
-        .collect_tuple()
-        .unwrap()
+fn parse_pairs(input: &str) -> AocResult<Vec<(CleaningRange, CleaningRange)>> {
+    parse_lines(input, parse_line)
 }
 
-fn count_containments(filename: &str) -> i32 {
-    read_lines(filename)
-        .unwrap()
+fn count_containments(pairs: &[(CleaningRange, CleaningRange)]) -> i32 {
+    pairs
         .iter()
-        .filter(|line| {
-            let (first, second) = parse_line(line);
-            first.contains_range(&second) || second.contains_range(&first)
-        })
+        .filter(|(first, second)| first.contains_range(second) || second.contains_range(first))
         .count() as i32
 }
 
-fn count_intersections(filename: &str) -> i32 {
-    read_lines(filename)
-        .unwrap()
+fn count_intersections(pairs: &[(CleaningRange, CleaningRange)]) -> i32 {
+    pairs
         .iter()
-        .filter(|line| {
-            let (first, second) = parse_line(line);
-            first.intersects_range(&second)
-        })
+        .filter(|(first, second)| first.intersects_range(second))
         .count() as i32
 }
 
 #[test]
 fn count_containments_test() {
-    assert_eq!(count_containments("./test1.txt"), 2);
+    assert_eq!(
+        count_containments(&parse_pairs(&read_input("./test1.txt").unwrap()).unwrap()),
+        2
+    );
 }
 
 #[test]
@@ -107,7 +142,7 @@ fn try_from_test() {
 fn parse_line_test() {
     assert_eq!(
         parse_line("2-4,6-8"),
-        (CleaningRange::new(2, 4), CleaningRange::new(6, 8))
+        Ok((CleaningRange::new(2, 4), CleaningRange::new(6, 8)))
     );
 }
 
@@ -144,7 +179,10 @@ fn intersects_range_test() {
 
 #[test]
 fn count_intersections_test() {
-    assert_eq!(count_intersections("./test1.txt"), 4);
+    assert_eq!(
+        count_intersections(&parse_pairs(&read_input("./test1.txt").unwrap()).unwrap()),
+        4
+    );
 }
 /*
 For example, consider the following list of section assignment pairs:
@@ -181,3 +219,22 @@ This example list uses single-digit section IDs to make it easier to draw; your
 ...45678.  4-8
 
 Some of the pairs have noticed that one of their assignments fully contains the other. For example, 2-8 fully contains 3-7, and 6-6 is fully contained by 4-6. In pairs where one assignment fully contains the other, one Elf in the pair would be exclusively cleaning sections their partner will already be cleaning, so these seem like the most in need of reconsideration. In this example, there are 2 such pairs. */
+
+#[test]
+fn parse_error_test() {
+    let e = parse_pairs("2-4,6-8\n2-3,4-x").unwrap_err();
+    assert_eq!((e.line, e.column), (Some(2), Some(7)));
+
+    let e = parse_pairs("2-4,8-6").unwrap_err();
+    assert_eq!((e.line, e.column), (Some(1), Some(5)));
+
+    assert!(parse_pairs("2-4").is_err());
+}
+
+#[test]
+fn write_pairs_test() {
+    let input = std::fs::read_to_string("./test1.txt").unwrap();
+    let pairs = parse_pairs(&input).unwrap();
+    assert_eq!(write_pairs(&pairs), input.trim_end().to_owned() + "\n");
+    assert_eq!(CleaningRange::new(2, 40).to_string(), "2-40");
+}
@@ -0,0 +1,81 @@
+use aoc_common::{Generated, Rng};
+use aoc_macros::aoc_generator;
+
+const STACKS: usize = 9;
+
+/// Nine stacks of crates and `size` moves. Each move leaves at least one
+/// crate behind, so both cranes can make every move and every stack keeps a
+/// top crate; the answers come from moving the crates alongside.
+#[aoc_generator(day = 5)]
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let start: Vec<Vec<char>> = (0..STACKS)
+        .map(|_| {
+            (0..rng.range(2..=8))
+                .map(|_| (b'A' + rng.range(0..=25) as u8) as char)
+                .collect()
+        })
+        .collect();
+
+    let mut input = drawing(&start);
+    input.push('\n');
+    let mut one_at_a_time = start.clone();
+    let mut all_at_once = start;
+    for _ in 0..size {
+        let tall: Vec<usize> = (0..STACKS)
+            .filter(|&i| one_at_a_time[i].len() >= 2)
+            .collect();
+        let from = *rng.choose(&tall);
+        let to = (from + rng.range(1..=STACKS as u64 - 1) as usize) % STACKS;
+        let count = rng.range(1..=(one_at_a_time[from].len() as u64 - 1).min(6)) as usize;
+        input.push_str(&format!("move {} from {} to {}\n", count, from + 1, to + 1));
+
+        for stacks in [&mut one_at_a_time, &mut all_at_once] {
+            let left = stacks[from].len() - count;
+            let lifted = stacks[from].split_off(left);
+            stacks[to].extend(lifted);
+        }
+        let moved = one_at_a_time[to].len() - count;
+        one_at_a_time[to][moved..].reverse();
+    }
+
+    let tops = |stacks: &[Vec<char>]| stacks.iter().filter_map(|s| s.last()).collect();
+    Generated {
+        input,
+        answers: Some([tops(&one_at_a_time), tops(&all_at_once)]),
+    }
+}
+
+/// The stacks as the puzzle draws them, bottom crate last, then the stack
+/// numbers.
+fn drawing(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut lines = vec![];
+    for level in (0..height).rev() {
+        let cells: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".to_owned(),
+            })
+            .collect();
+        lines.push(cells.join(" "));
+    }
+    let numbers: Vec<String> = (1..=stacks.len()).map(|n| format!(" {} ", n)).collect();
+    lines.push(numbers.join(" "));
+    lines.join("\n") + "\n"
+}
+
+#[test]
+fn drawing_test() {
+    let stacks = [vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
+    assert_eq!(
+        drawing(&stacks),
+        std::fs::read_to_string("test1.txt")
+            .unwrap()
+            .split("\n\n")
+            .next()
+            .unwrap()
+            .to_owned()
+            + "\n"
+    );
+}
@@ -0,0 +1,280 @@
+mod generate;
+
+use std::fmt::Debug;
+
+use aoc_common::{parse, AocError, AocResult, Sections, Solution};
+use aoc_macros::aoc;
+use tracing::{debug, trace};
+
+#[cfg(test)]
+use aoc_common::read_input;
+
+pub struct Day5;
+
+#[aoc(day = 5)]
+impl Solution for Day5 {
+    type Input<'a> = (Game, Vec<Move>);
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> AocResult<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> AocResult<Self::Part1> {
+        top_after_9000(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> AocResult<Self::Part2> {
+        top_after_9001(input)
+    }
+}
+
+fn top_after_9000((game, instructions): &(Game, Vec<Move>)) -> AocResult<String> {
+    Ok(play_game(game, instructions, Crane::CrateMover9000)?.get_stack_top())
+}
+
+fn top_after_9001((game, instructions): &(Game, Vec<Move>)) -> AocResult<String> {
+    Ok(play_game(game, instructions, Crane::CrateMover9001)?.get_stack_top())
+}
+
+fn parse_input(input: &str) -> AocResult<(Game, Vec<Move>)> {
+    let mut sections = Sections::new(input);
+    let board_lines = sections.next().map(|s| s.text).unwrap_or_default();
+    let board: Vec<Vec<_>> = board_lines
+        .lines()
+        .map(|l| l.chars().collect::<Vec<_>>())
+        // Kind of janky, but take until we get the column definitions (which are the only numerals)
+        .take_while(|x| x.get(1) != Some(&'1'))
+        .collect();
+
+    let instructions = sections.next();
+
+    // Game board with no columns, we'll handle that as we add them.
+    let mut game_board = Game { stacks: vec![] };
+
+    for line in board.iter().rev() {
+        let parse_board = line
+            .chunks(4)
+            .map(|col_val| col_val.get(1).copied().unwrap_or(' '))
+            .enumerate()
+            .filter(|(_, v)| !v.is_whitespace());
+
+        for (column, value) in parse_board {
+            game_board.add(value, column + 1);
+        }
+    }
+
+    let instructions = instructions
+        .into_iter()
+        .flat_map(|section| section.lines().zip(section.first_line..))
+        .map(|(line, number)| parse_move(line).map_err(|e| e.at_line(number, line)))
+        .collect::<AocResult<Vec<_>>>()?;
+
+    Ok((game_board, instructions))
+}
+
+fn parse_move(line: &str) -> AocResult<Move> {
+    let (count, from, to) = parse!("move {} from {} to {}", line)?;
+    Ok(Move::new(from, to, count))
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Move {
+    from: usize,
+    to: usize,
+    count: usize,
+}
+
+impl Move {
+    #[cfg(test)]
+    fn new_single(from: usize, to: usize) -> Move {
+        Move { from, to, count: 1 }
+    }
+
+    fn new(from: usize, to: usize, count: usize) -> Move {
+        Move { from, to, count }
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct Game {
+    stacks: Vec<Vec<char>>,
+}
+
+/// The 9000 lifts one crate at a time, the 9001 lifts the whole pile at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Crane {
+    CrateMover9000,
+    CrateMover9001,
+}
+
+impl Game {
+    fn add(&mut self, val: char, col: usize) {
+        while self.stacks.len() < col {
+            self.stacks.push(vec![]);
+        }
+
+        // Get columns back to indexes.
+        let col = col - 1;
+
+        self.stacks
+            .get_mut(col)
+            .expect("adding failed because the column is missing")
+            .push(val);
+    }
+
+    fn make_move(&mut self, the_move: &Move, crane: Crane) -> AocResult<()> {
+        let source = the_move.from;
+        let dest = the_move.to;
+
+        let size = self.stacks.len();
+        if source < 1 || source > size {
+            return Err(AocError::solve(format!(
+                "source stack {} does not exist",
+                source
+            )));
+        } else if dest < 1 || dest > size {
+            return Err(AocError::solve(format!(
+                "destination stack {} does not exist",
+                dest
+            )));
+        }
+
+        let source = source - 1;
+        let dest = dest - 1;
+        let mut move_stack: Vec<char> = Vec::new();
+        let source_col = self
+            .stacks
+            .get_mut(source)
+            .expect("destination column should exist");
+
+        for _ in 1..the_move.count + 1 {
+            move_stack.push(source_col.pop().ok_or_else(|| {
+                AocError::solve(format!(
+                    "stack {} ran out of crates moving {}",
+                    the_move.from, the_move.count
+                ))
+            })?);
+        }
+
+        let desitination_col = self
+            .stacks
+            .get_mut(dest)
+            .expect("source column should exist");
+
+        match crane {
+            Crane::CrateMover9000 => desitination_col.extend(move_stack),
+            Crane::CrateMover9001 => desitination_col.extend(move_stack.iter().rev()),
+        }
+
+        Ok(())
+    }
+
+    fn get_stack_top(&self) -> String {
+        let mut top = String::new();
+        for stack in &self.stacks {
+            if let Some(val) = stack.last() {
+                top.push(*val)
+            };
+        }
+        top
+    }
+}
+
+fn play_game(game: &Game, instructions: &[Move], crane: Crane) -> AocResult<Game> {
+    let mut game = game.clone();
+
+    for (i, m) in instructions.iter().enumerate() {
+        debug!(
+            step = i + 1,
+            count = m.count,
+            from = m.from,
+            to = m.to,
+            ?crane,
+            "crane move"
+        );
+        game.make_move(m, crane)
+            .map_err(|e| AocError::solve(format!("move {}: {}", i + 1, e.message)))?;
+        trace!(stacks = ?game.stacks, "after move");
+    }
+
+    Ok(game)
+}
+
+#[test]
+fn parse_board_test() {
+    let (game, instructions) = parse_input(&read_input("./test1.txt").unwrap()).unwrap();
+
+    // Write some real asserts here
+    assert_eq!(game.stacks[0], vec!['Z', 'N']);
+    assert_eq!(game.stacks[1], vec!['M', 'C', 'D']);
+    assert_eq!(game.stacks[2], vec!['P']);
+
+    assert_eq!(
+        instructions,
+        vec![
+            Move::new_single(2, 1),
+            Move::new(1, 3, 3),
+            Move::new(2, 1, 2),
+            Move::new_single(1, 2),
+        ]
+    )
+}
+
+#[test]
+fn parse_board_pop_test() {
+    let (mut game, _) = parse_input(&read_input("./test1.txt").unwrap()).unwrap();
+    assert_eq!(game.get_stack_top(), "NDP");
+
+    assert_eq!(game.stacks[0], vec!['Z', 'N']);
+    assert_eq!(game.stacks[1], vec!['M', 'C', 'D']);
+    assert_eq!(game.stacks[2], vec!['P']);
+
+    game.make_move(&Move::new_single(1, 2), Crane::CrateMover9001)
+        .unwrap();
+    assert_eq!(game.stacks[1], vec!['M', 'C', 'D', 'N']);
+    assert_eq!(game.get_stack_top(), "ZNP");
+    game.make_move(&Move::new_single(3, 1), Crane::CrateMover9001)
+        .unwrap();
+    assert_eq!(game.get_stack_top(), "PN");
+}
+
+#[test]
+fn get_stack_top_test() {
+    let g = Game {
+        stacks: vec![vec!['A', 'B'], vec!['C', 'D'], vec!['E', 'F', 'G']],
+    };
+
+    assert_eq!(g.get_stack_top(), "BDG")
+}
+
+#[test]
+fn play_game_test() {
+    let (game, instructions) = parse_input(&read_input("./test1.txt").unwrap()).unwrap();
+    let game = play_game(&game, &instructions, Crane::CrateMover9001).unwrap();
+
+    assert_eq!(game.get_stack_top(), "MCD");
+}
+
+#[test]
+fn play_game_one_at_a_time_test() {
+    let (game, instructions) = parse_input(&read_input("./test1.txt").unwrap()).unwrap();
+    let game = play_game(&game, &instructions, Crane::CrateMover9000).unwrap();
+
+    assert_eq!(game.get_stack_top(), "CMZ");
+}
+
+#[test]
+fn parse_move_error_test() {
+    let e = parse_input("[A]\n 1 \n\nmove 1 from 1 to 1\nmove x from 1 to 2").unwrap_err();
+    assert_eq!((e.line, e.column), (Some(5), Some(6)));
+}
+
+#[test]
+fn play_game_error_test() {
+    let (game, instructions) = parse_input("[A]\n 1 \n\nmove 2 from 1 to 1").unwrap();
+    let e = play_game(&game, &instructions, Crane::CrateMover9000).unwrap_err();
+    assert_eq!(e.message, "move 1: stack 1 ran out of crates moving 2");
+}
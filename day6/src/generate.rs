@@ -0,0 +1,37 @@
+use aoc_common::{Generated, Rng};
+use aoc_macros::aoc_generator;
+
+/// A signal `size` letters long (at least 32), with both markers placed.
+///
+/// Before the start-of-packet marker only three letters are used, so no four
+/// in a row differ until a fourth letter ends the marker. Before the
+/// start-of-message marker only thirteen letters are used (those four among
+/// them), which ends with thirteen different ones and then a fourteenth.
+#[aoc_generator(day = 6)]
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(32);
+    let mut letters: Vec<u8> = (b'a'..=b'z').collect();
+    rng.shuffle(&mut letters);
+    let (three, thirteen) = (&letters[..3], &letters[..13]);
+
+    let packet = rng.range(4..=size as u64 / 2) as usize;
+    let message = rng.range(packet as u64 + 14..=size as u64) as usize;
+
+    let mut signal: Vec<u8> = (0..packet - 4).map(|_| *rng.choose(three)).collect();
+    let mut ending = three.to_vec();
+    rng.shuffle(&mut ending);
+    signal.extend(ending);
+    signal.push(letters[3]);
+
+    signal.extend((packet..message - 14).map(|_| *rng.choose(thirteen)));
+    let mut ending = thirteen.to_vec();
+    rng.shuffle(&mut ending);
+    signal.extend(ending);
+    signal.push(letters[13]);
+
+    signal.extend((message..size).map(|_| *rng.choose(&letters)));
+    Generated {
+        input: String::from_utf8(signal).expect("the signal is all letters") + "\n",
+        answers: Some([packet.to_string(), message.to_string()]),
+    }
+}
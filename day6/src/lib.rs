@@ -0,0 +1,241 @@
+mod generate;
+
+use std::{
+    collections::VecDeque,
+    io::{BufRead, Read},
+    str::FromStr,
+};
+
+use aoc_common::{AocError, AocResult, Solution};
+use aoc_macros::aoc;
+use tracing::{debug, trace};
+
+struct SlidingWindow {
+    window_size: usize,
+    window: VecDeque<char>,
+    word_count: VecDeque<u8>,
+}
+
+impl SlidingWindow {
+    fn new(window_size: usize) -> Self {
+        Self {
+            window_size,
+            window: VecDeque::new(),
+            word_count: (1..27).map(|_| 0).collect(),
+        }
+    }
+
+    fn cycle_char(&mut self, next: char) -> u8 {
+        assert!(self.window.len() <= self.window_size);
+
+        let next = next.to_ascii_lowercase();
+
+        if self.window.len() == self.window_size {
+            let remove_char = self.window.pop_front().unwrap();
+            self.word_count[remove_char as usize - 97] -= 1;
+        }
+
+        self.window.push_back(next);
+        self.word_count[next as usize - 97] += 1;
+
+        let unique = self.count_unique_chars();
+        trace!(size = self.window_size, %next, unique, "window moved");
+        unique
+    }
+
+    fn count_unique_chars(&self) -> u8 {
+        self.word_count
+            .iter()
+            .filter_map(|x| if *x == 0u8 { None } else { Some(1u8) })
+            .sum()
+    }
+}
+
+pub struct Signal {
+    start_offset: usize,
+    start_of_message: usize,
+}
+
+impl Signal {
+    /// Read the signal a character at a time, up to the end of the line, and
+    /// stop as soon as the start-of-message marker turns up.
+    fn scan(chars: impl Iterator<Item = AocResult<char>>) -> AocResult<Signal> {
+        let mut packet = SlidingWindow::new(4);
+        let mut message = SlidingWindow::new(14);
+        let mut start_offset = None;
+
+        for (i, c) in chars.enumerate() {
+            let c = c?;
+            if c == '\n' || c == '\r' {
+                break;
+            }
+            if !c.is_ascii_alphabetic() {
+                return Err(AocError::parse("the signal must only contain letters")
+                    .on_line(1)
+                    .at_column(i + 1));
+            }
+
+            if start_offset.is_none() && packet.cycle_char(c) == 4 {
+                debug!(offset = i + 1, "start-of-packet marker");
+                start_offset = Some(i + 1);
+            }
+            if message.cycle_char(c) == 14 {
+                debug!(offset = i + 1, "start-of-message marker");
+                return Ok(Signal {
+                    // Fourteen different letters always include four.
+                    start_offset: start_offset.unwrap_or(i + 1),
+                    start_of_message: i + 1,
+                });
+            }
+        }
+
+        Err(AocError::solve("the signal has no start-of-message marker"))
+    }
+}
+
+impl FromStr for Signal {
+    type Err = AocError;
+
+    fn from_str(full_signal: &str) -> Result<Self, Self::Err> {
+        Signal::scan(full_signal.chars().map(Ok)).map_err(|e| match e.column {
+            Some(_) => e.with_text(full_signal),
+            None => e,
+        })
+    }
+}
+
+pub struct Day6;
+
+#[aoc(day = 6)]
+impl Solution for Day6 {
+    type Input<'a> = Signal;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> AocResult<Self::Input<'_>> {
+        parse_signal(input)
+    }
+
+    fn parse_reader(reader: &mut dyn BufRead) -> Option<AocResult<Self::Input<'static>>> {
+        Some(read_signal(reader))
+    }
+
+    fn part1(signal: &Self::Input<'_>) -> AocResult<Self::Part1> {
+        Ok(start_of_packet(signal))
+    }
+
+    fn part2(signal: &Self::Input<'_>) -> AocResult<Self::Part2> {
+        Ok(start_of_message(signal))
+    }
+}
+
+fn parse_signal(input: &str) -> AocResult<Signal> {
+    input.trim_end().parse()
+}
+
+fn read_signal(reader: &mut dyn BufRead) -> AocResult<Signal> {
+    Signal::scan(
+        reader
+            .bytes()
+            .map(|byte| byte.map(char::from).map_err(AocError::from)),
+    )
+}
+
+fn start_of_packet(signal: &Signal) -> usize {
+    signal.start_offset
+}
+
+fn start_of_message(signal: &Signal) -> usize {
+    signal.start_of_message
+}
+
+#[test]
+fn cycle_char_4_test() {
+    let mut window = SlidingWindow::new(4);
+    // Add the sequence "aabb" - which should give us a unique character count of 2.
+    let letter_count = window.cycle_char('a');
+    assert_eq!(letter_count, 1u8);
+
+    let letter_count = window.cycle_char('a');
+    assert_eq!(letter_count, 1u8);
+
+    let letter_count = window.cycle_char('b');
+    assert_eq!(letter_count, 2u8);
+
+    let letter_count = window.cycle_char('b');
+    assert_eq!(letter_count, 2u8);
+
+    // Now, add acd - which should get us up to a unique character count of 4.
+    let letter_count = window.cycle_char('a');
+    assert_eq!(letter_count, 2u8);
+
+    let letter_count = window.cycle_char('c');
+    assert_eq!(letter_count, 3u8);
+
+    let letter_count = window.cycle_char('d');
+    assert_eq!(letter_count, 4u8);
+}
+/*
+mjqjpqmgbljsphdztnvjfqwrcgsmlb - In this case, your subroutine should report the value 7, because the first start-of-packet marker is complete after 7 characters have been processed.
+bvwbjplbgvbhsrlpgdmjqwftvncz: first marker after character 5
+nppdvjthqldpwncqszvftbrmjlhg: first marker after character 6
+nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg: first marker after character 10
+zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw: first marker after character 11
+ */
+#[test]
+fn signal_start_test_1() {
+    let s = "mjqjpqmgbljsphdztnvjfqwrcgsmlb".parse::<Signal>().unwrap();
+    assert_eq!(s.start_offset, 7);
+    assert_eq!(s.start_of_message, 19);
+}
+
+#[test]
+fn signal_start_test_2() {
+    let s = "bvwbjplbgvbhsrlpgdmjqwftvncz".parse::<Signal>().unwrap();
+    assert_eq!(s.start_offset, 5);
+    assert_eq!(s.start_of_message, 23);
+}
+
+#[test]
+fn signal_start_test_3() {
+    let s = "nppdvjthqldpwncqszvftbrmjlhg".parse::<Signal>().unwrap();
+    assert_eq!(s.start_offset, 6);
+    assert_eq!(s.start_of_message, 23);
+}
+
+#[test]
+fn signal_start_test_4() {
+    let s = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"
+        .parse::<Signal>()
+        .unwrap();
+    assert_eq!(s.start_offset, 10);
+    assert_eq!(s.start_of_message, 29);
+}
+
+#[test]
+fn signal_start_test_5() {
+    let s = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"
+        .parse::<Signal>()
+        .unwrap();
+    assert_eq!(s.start_offset, 11);
+    assert_eq!(s.start_of_message, 26);
+}
+
+#[test]
+fn signal_errors_test() {
+    let e = "abc1".parse::<Signal>().err().unwrap();
+    assert_eq!(e.column, Some(4));
+
+    let e = "abcabc".parse::<Signal>().err().unwrap();
+    assert_eq!(e.message, "the signal has no start-of-message marker");
+}
+
+#[test]
+fn parse_reader_test() {
+    let mut input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n".as_bytes();
+    let s = read_signal(&mut input).unwrap();
+    assert_eq!((s.start_offset, s.start_of_message), (7, 19));
+
+    let e = read_signal(&mut "abc1".as_bytes()).err().unwrap();
+    assert_eq!((e.line, e.column), (Some(1), Some(4)));
+}
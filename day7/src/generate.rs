@@ -0,0 +1,120 @@
+use aoc_common::{Generated, Rng};
+use aoc_macros::aoc_generator;
+
+const EXTENSIONS: [&str; 5] = ["", ".txt", ".dat", ".lst", ".log"];
+
+#[derive(Default)]
+struct Directory {
+    name: String,
+    /// Indexes of the directories inside this one, which are always later in
+    /// the list than it.
+    directories: Vec<usize>,
+    files: Vec<(String, u64)>,
+}
+
+/// `a`, `b`, ... `z`, `ba`, ...: a different name for every number.
+fn name(mut n: usize) -> String {
+    let mut name = vec![];
+    loop {
+        name.push(b'a' + (n % 26) as u8);
+        n /= 26;
+        if n == 0 {
+            break;
+        }
+    }
+    name.reverse();
+    String::from_utf8(name).expect("names are letters")
+}
+
+/// A terminal session listing a tree of `size` files. The disk is always
+/// 45 to 50 million full, so part 2 has something to delete.
+#[aoc_generator(day = 7)]
+fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut tree = vec![Directory::default()];
+    for i in 1..=size / 4 {
+        let parent = rng.index(tree.len());
+        tree[parent].directories.push(i);
+        tree.push(Directory {
+            name: name(i),
+            ..Directory::default()
+        });
+    }
+
+    let largest = (50_000_000 / size.max(1) as u64).clamp(1, 300_000);
+    let mut used = 0;
+    for i in 0..size {
+        let file_size = rng.range(1..=largest);
+        used += file_size;
+        let file = name(i) + *rng.choose(&EXTENSIONS);
+        let directory = rng.index(tree.len());
+        tree[directory].files.push((file, file_size));
+    }
+    if used < 45_000_000 {
+        let padding = 45_000_000 - used + rng.range(0..=5_000_000);
+        used += padding;
+        tree[0].files.push((name(size) + ".bin", padding));
+    }
+
+    let mut sizes: Vec<u64> = tree
+        .iter()
+        .map(|d| d.files.iter().map(|(_, size)| size).sum())
+        .collect();
+    for (i, directory) in tree.iter().enumerate().rev() {
+        sizes[i] += directory.directories.iter().map(|&d| sizes[d]).sum::<u64>();
+    }
+    let needed = 30_000_000 - (70_000_000 - used);
+
+    let mut input = "$ cd /\n".to_owned();
+    session(rng, &tree, 0, &mut input);
+    Generated {
+        input,
+        answers: Some([
+            sizes
+                .iter()
+                .filter(|&&size| size <= 100_000)
+                .sum::<u64>()
+                .to_string(),
+            sizes
+                .iter()
+                .filter(|&&size| size >= needed)
+                .min()
+                .expect("the root is big enough")
+                .to_string(),
+        ]),
+    }
+}
+
+/// `ls` in `tree[index]`, then the same in each directory inside it.
+fn session(rng: &mut Rng, tree: &[Directory], index: usize, out: &mut String) {
+    let directory = &tree[index];
+    let mut listing: Vec<String> = directory
+        .directories
+        .iter()
+        .map(|&d| format!("dir {}", tree[d].name))
+        .chain(
+            directory
+                .files
+                .iter()
+                .map(|(name, size)| format!("{} {}", size, name)),
+        )
+        .collect();
+    rng.shuffle(&mut listing);
+
+    out.push_str("$ ls\n");
+    for line in listing {
+        out.push_str(&line);
+        out.push('\n');
+    }
+    for &d in &directory.directories {
+        out.push_str(&format!("$ cd {}\n", tree[d].name));
+        session(rng, tree, d, out);
+        out.push_str("$ cd ..\n");
+    }
+}
+
+#[test]
+fn name_test() {
+    assert_eq!(name(0), "a");
+    assert_eq!(name(25), "z");
+    assert_eq!(name(26), "ba");
+}
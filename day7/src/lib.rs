@@ -0,0 +1,254 @@
+mod generate;
+mod new_tree;
+mod terminal_parser;
+
+use std::{cell::RefCell, rc::Rc};
+
+use aoc_common::{parse::parse_lines, AocError, AocResult, Solution};
+use aoc_macros::aoc;
+use new_tree::*;
+pub use terminal_parser::*;
+use tracing::{debug, trace};
+
+#[cfg(test)]
+use aoc_common::read_input;
+
+/// The transcript a line at a time, for editing and writing back out with
+/// `write_terminal`.
+pub fn parse_terminal(input: &str) -> AocResult<Vec<Terminal<'_>>> {
+    parse_lines(input, Terminal::try_from)
+}
+
+pub struct Day7;
+
+#[aoc(day = 7)]
+impl Solution for Day7 {
+    type Input<'a> = Rc<RefCell<DirectoryNode<'a>>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> AocResult<Self::Input<'_>> {
+        parse_tree(input)
+    }
+
+    fn part1(root: &Self::Input<'_>) -> AocResult<Self::Part1> {
+        Ok(small_directories_total(root))
+    }
+
+    fn part2(root: &Self::Input<'_>) -> AocResult<Self::Part2> {
+        directory_to_delete(root)
+    }
+}
+
+fn parse_tree(input: &str) -> AocResult<Rc<RefCell<DirectoryNode<'_>>>> {
+    let commands = parse_terminal(input)?;
+
+    process_commands(commands.into_iter()).map_err(|e| match e.line {
+        Some(line) => e.with_text(input.lines().nth(line - 1).unwrap_or_default()),
+        None => e,
+    })
+}
+
+fn small_directories_total(root: &Rc<RefCell<DirectoryNode<'_>>>) -> usize {
+    let directories = flatten_directories(Rc::clone(root));
+    directories
+        .iter()
+        .filter_map(|(_, size)| if size <= &100000 { Some(size) } else { None })
+        .sum::<usize>()
+}
+
+fn directory_to_delete(root: &Rc<RefCell<DirectoryNode<'_>>>) -> AocResult<usize> {
+    let directories = flatten_directories(Rc::clone(root));
+    let free = 70000000usize
+        .checked_sub(root.borrow().size)
+        .ok_or_else(|| AocError::solve("the files do not fit on the disk"))?;
+    let needed = 30000000usize
+        .checked_sub(free)
+        .ok_or_else(|| AocError::solve("there is already enough free space"))?;
+
+    let mut smallest = directories
+        .iter()
+        .filter(|(_, size)| size >= &needed)
+        .collect::<Vec<_>>();
+
+    smallest.sort_by_key(|(_, size)| size);
+    smallest
+        .first()
+        .map(|(_, size)| *size)
+        .ok_or_else(|| AocError::solve("no directory is big enough to delete"))
+}
+
+fn process_commands<'a, T: Iterator<Item = Terminal<'a>>>(
+    lines: T,
+) -> AocResult<Rc<RefCell<DirectoryNode<'a>>>> {
+    let root = DirectoryNode::new(None, "/");
+    let mut current_location = Rc::clone(&root);
+
+    for (i, line) in lines.enumerate() {
+        match line {
+            Terminal::Noop => (),
+            Terminal::Command(command) => match command {
+                TermCommand::Cd(cd) => match cd {
+                    ChangeDir::In(into) => {
+                        debug!(line = i + 1, directory = %into, "cd");
+                        let child = current_location.borrow().get_child(into);
+                        current_location = match child {
+                            Some(child) => child,
+                            None => {
+                                return Err(AocError::parse(format!(
+                                    "cd into unknown directory `{}`",
+                                    into
+                                ))
+                                .on_line(i + 1));
+                            }
+                        }
+                    }
+                    ChangeDir::Out => {
+                        debug!(line = i + 1, "cd ..");
+                        let parent = match &(*current_location).borrow().parent {
+                            Some(x) => Rc::clone(x),
+                            None => {
+                                return Err(
+                                    AocError::parse("attempted to move up too far").on_line(i + 1)
+                                );
+                            }
+                        };
+
+                        current_location = parent;
+                    }
+                    ChangeDir::Root => {
+                        debug!(line = i + 1, "cd /");
+                        current_location = Rc::clone(&root)
+                    }
+                },
+                TermCommand::Ls => (),
+            },
+            Terminal::LsResult(ls_result) => match ls_result {
+                LsResult::FileContents(file) => {
+                    trace!(file = %file.filename, size = file.size, "file");
+                    current_location
+                        .borrow_mut()
+                        .add_child_file(file.filename, file.size)
+                }
+                LsResult::Directory(name) => {
+                    let new_node = DirectoryNode::new(Some(Rc::clone(&current_location)), name);
+                    current_location.borrow_mut().add_child_dir(new_node);
+                }
+            },
+        }
+    }
+
+    Ok(Rc::clone(&root))
+}
+
+fn flatten_directories<'a>(root: Rc<RefCell<DirectoryNode<'a>>>) -> Vec<(&'a str, usize)> {
+    let mut to_walk = vec![root];
+    let mut dirs = vec![];
+
+    while let Some(next) = to_walk.pop() {
+        let next = next.borrow();
+        dirs.push((next.name, next.size));
+        for child in &next.directory_children {
+            to_walk.push(Rc::clone(child));
+        }
+    }
+
+    dirs
+}
+
+#[test]
+fn file_children_test() {
+    let root = process_commands(
+        vec![
+            Terminal::Command(TermCommand::Cd(ChangeDir::Root)),
+            Terminal::LsResult(LsResult::FileContents(terminal_parser::FileData {
+                size: 14,
+                filename: "unk.png",
+            })),
+        ]
+        .into_iter(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        *root.borrow(),
+        DirectoryNode {
+            parent: None,
+            name: "/",
+            size: 14,
+            directory_children: vec![],
+            file_children: vec!["unk.png"].into_iter().collect(),
+        }
+    );
+}
+
+#[test]
+fn read_file_test() {
+    let input = read_input("./test.txt").unwrap();
+    let root = parse_tree(&input).unwrap();
+    assert_eq!(root.borrow().size, 48381165);
+
+    assert_eq!(
+        flatten_directories(root)
+            .iter()
+            .filter_map(|(_, size)| if size <= &100000 { Some(size) } else { None })
+            .sum::<usize>(),
+        95437
+    );
+}
+
+#[test]
+fn small_thing_test() {
+    let input = read_input("./test.txt").unwrap();
+    let root = parse_tree(&input).unwrap();
+    let needed = 30000000 - (70000000 - root.borrow().size);
+
+    let directories = flatten_directories(Rc::clone(&root));
+    let mut smallest = directories
+        .iter()
+        .filter(|(_, size)| size >= &needed)
+        .collect::<Vec<_>>();
+
+    smallest.sort_by_key(|(_, size)| size);
+    let solution = *smallest.first().unwrap();
+    assert_eq!(solution.1, 24933642);
+}
+
+#[test]
+fn unknown_directory_test() {
+    let e = parse_tree("$ cd /\n$ ls\ndir a\n$ cd b").unwrap_err();
+    assert_eq!(e.line, Some(4));
+    assert_eq!(e.text.as_deref(), Some("$ cd b"));
+    assert_eq!(e.message, "cd into unknown directory `b`");
+}
+
+#[test]
+fn bad_line_test() {
+    let e = parse_tree("$ cd /\n$ mkdir a").unwrap_err();
+    assert_eq!(e.line, Some(2));
+}
+
+#[test]
+fn boundary_sizes_test() {
+    // The puzzle counts directories of "at most 100000", and any directory
+    // that frees "enough" space: `a` is exactly the one, `b` exactly the other.
+    let root = parse_tree(
+        "$ cd /\n$ ls\ndir a\ndir b\n39900000 c\n$ cd a\n$ ls\n100000 d\n$ cd ..\n$ cd b\n$ ls\n5000000 e\n",
+    )
+    .unwrap();
+    assert_eq!(small_directories_total(&root), 100000);
+    assert_eq!(directory_to_delete(&root), Ok(5000000));
+}
+
+#[test]
+fn edit_transcript_test() {
+    let input = std::fs::read_to_string("./test.txt").unwrap();
+    let mut lines = parse_terminal(&input).unwrap();
+    // Drop the 14848514 byte b.txt from the root.
+    lines.retain(|line| line.to_string() != "14848514 b.txt");
+
+    let edited = write_terminal(&lines);
+    let root = parse_tree(&edited).unwrap();
+    assert_eq!(root.borrow().size, 48381165 - 14848514);
+    assert_eq!(parse_terminal(&edited).unwrap(), lines);
+}
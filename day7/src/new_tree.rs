@@ -1,16 +1,17 @@
 use std::{cell::RefCell, collections::HashSet, rc::Rc};
 
 #[derive(Debug, PartialEq, Eq)]
-pub struct DirectoryNode {
-    pub parent: Option<Rc<RefCell<DirectoryNode>>>,
-    pub name: String,
+/// A directory, naming it and its files with slices of the transcript.
+pub struct DirectoryNode<'a> {
+    pub parent: Option<Rc<RefCell<DirectoryNode<'a>>>>,
+    pub name: &'a str,
     pub size: usize,
-    pub directory_children: Vec<Rc<RefCell<DirectoryNode>>>,
-    pub file_children: HashSet<String>,
+    pub directory_children: Vec<Rc<RefCell<DirectoryNode<'a>>>>,
+    pub file_children: HashSet<&'a str>,
 }
 
-impl DirectoryNode {
-    pub fn new(parent: Option<Rc<RefCell<DirectoryNode>>>, name: String) -> Rc<RefCell<Self>> {
+impl<'a> DirectoryNode<'a> {
+    pub fn new(parent: Option<Rc<RefCell<DirectoryNode<'a>>>>, name: &'a str) -> Rc<RefCell<Self>> {
         Rc::new(RefCell::new(Self {
             parent,
             name,
@@ -21,13 +22,13 @@ impl DirectoryNode {
     }
 
     pub fn get_child(&self, name: &str) -> Option<Rc<RefCell<Self>>> {
-            self.directory_children
-                .iter()
-                .find(|&x| (*x).borrow().name == name)
-                .map(|x| Rc::clone(x))
+        self.directory_children
+            .iter()
+            .find(|&x| (*x).borrow().name == name)
+            .map(Rc::clone)
     }
 
-    pub fn add_child_dir(&mut self, new: Rc<RefCell<DirectoryNode>>) {
+    pub fn add_child_dir(&mut self, new: Rc<RefCell<DirectoryNode<'a>>>) {
         let new_size = new.borrow().size;
         if new_size > 0 {
             self.size += new_size;
@@ -42,10 +43,9 @@ impl DirectoryNode {
         if let Some(parent) = &self.parent {
             parent.borrow_mut().increase_size(size);
         }
-
     }
 
-    pub fn add_child_file(&mut self, name: String, size: usize) {
+    pub fn add_child_file(&mut self, name: &'a str, size: usize) {
         if self.file_children.insert(name) {
             self.increase_size(size)
         }
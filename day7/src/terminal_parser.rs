@@ -1,137 +1,211 @@
-use std::str::FromStr;
+use std::fmt::Display;
+
+use aoc_common::{parse, template::try_parse, AocError};
 
 #[derive(Debug, PartialEq)]
-pub(crate) enum Terminal {
-    Command(TermCommand),
-    LsResult(LsResult),
+pub enum Terminal<'a> {
+    Command(TermCommand<'a>),
+    LsResult(LsResult<'a>),
     Noop,
 }
 
 #[derive(Debug, PartialEq)]
-pub(crate) enum TermCommand {
-    Cd(ChangeDir),
+pub enum TermCommand<'a> {
+    Cd(ChangeDir<'a>),
     Ls,
 }
 
 #[derive(Debug, PartialEq)]
-pub(crate) enum ChangeDir {
-    In(String),
+pub enum ChangeDir<'a> {
+    In(&'a str),
     Out,
     Root,
 }
 
-impl FromStr for ChangeDir {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl<'a> From<&'a str> for ChangeDir<'a> {
+    fn from(s: &'a str) -> Self {
         match s {
-            ".." => Ok(Self::Out),
-            "/" => Ok(Self::Root),
-            other => Ok(Self::In(other.to_owned())),
+            ".." => Self::Out,
+            "/" => Self::Root,
+            other => Self::In(other),
         }
     }
 }
 
 #[derive(Debug, PartialEq)]
-pub(crate) enum LsResult {
-    FileContents(FileData),
-    Directory(String),
+pub enum LsResult<'a> {
+    FileContents(FileData<'a>),
+    Directory(&'a str),
 }
 
 #[derive(Debug, PartialEq)]
-pub(crate) struct FileData {
-    pub(crate) size: usize,
-    pub(crate) filename: String,
+pub struct FileData<'a> {
+    pub size: usize,
+    pub filename: &'a str,
 }
 
-impl FromStr for TermCommand {
-    type Err = String;
+impl<'a> TryFrom<&'a str> for TermCommand<'a> {
+    type Error = AocError;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let c: Vec<_> = s.split_whitespace().collect();
-        match c[1..] {
-            ["cd", target] => Ok(Self::Cd(target.parse().unwrap())),
-            ["ls"] => Ok(Self::Ls),
-            _ => Err(format!("Unknown command {}", s)),
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        if let Some(cd) = try_parse("$ cd {}", s) {
+            let (target,): (&str,) = cd?;
+            Ok(Self::Cd(target.into()))
+        } else if s == "$ ls" {
+            Ok(Self::Ls)
+        } else {
+            Err(AocError::parse(format!("unknown command `{}`", s)))
         }
     }
 }
 
-impl FromStr for LsResult {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let line: Vec<_> = s.split_whitespace().collect();
-        match line[0] {
-            "dir" => Ok(Self::Directory(line[1].to_owned())),
-            other => match other.parse::<usize>() {
-                Ok(size) => Ok(Self::FileContents(FileData {
-                    size,
-                    filename: line[1].to_owned(),
-                })),
-                Err(_) => Err(format!("Unable to parse {}", other)),
-            },
+impl<'a> TryFrom<&'a str> for LsResult<'a> {
+    type Error = AocError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        if let Some(directory) = try_parse("dir {}", s) {
+            let (name,) = directory?;
+            return Ok(Self::Directory(name));
         }
+        let (size, filename) = parse!("{} {}", s)?;
+        Ok(Self::FileContents(FileData { size, filename }))
     }
 }
-impl FromStr for Terminal {
-    type Err = String;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+/// A line of the transcript, borrowing its names from the line.
+impl<'a> TryFrom<&'a str> for Terminal<'a> {
+    type Error = AocError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
         if s.is_empty() {
-            return Ok(Self::Noop);
-        } else if &s[0..1] == "$" {
-            return Ok(Terminal::Command(s.parse()?));
+            Ok(Self::Noop)
+        } else if s.starts_with('$') {
+            Ok(Terminal::Command(s.try_into()?))
         } else {
-            return Ok(Terminal::LsResult(s.parse()?));
+            Ok(Terminal::LsResult(s.try_into()?))
+        }
+    }
+}
+
+impl Display for ChangeDir<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::In(directory) => write!(f, "{}", directory),
+            Self::Out => write!(f, ".."),
+            Self::Root => write!(f, "/"),
         }
     }
 }
 
+impl Display for TermCommand<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cd(target) => write!(f, "$ cd {}", target),
+            Self::Ls => write!(f, "$ ls"),
+        }
+    }
+}
+
+impl Display for LsResult<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FileContents(FileData { size, filename }) => write!(f, "{} {}", size, filename),
+            Self::Directory(name) => write!(f, "dir {}", name),
+        }
+    }
+}
+
+/// A line of the transcript as it was typed or printed.
+impl Display for Terminal<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Command(command) => write!(f, "{}", command),
+            Self::LsResult(result) => write!(f, "{}", result),
+            Self::Noop => Ok(()),
+        }
+    }
+}
+
+/// The transcript for `lines`, one to a line.
+pub fn write_terminal(lines: &[Terminal]) -> String {
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
 #[test]
 fn parse_ls_test() {
-    let t: Terminal = "$ ls".parse().unwrap();
+    let t = Terminal::try_from("$ ls").unwrap();
     assert_eq!(t, Terminal::Command(TermCommand::Ls));
 }
 
 #[test]
 fn parse_cd_root_test() {
-    let t: Terminal = "$ cd /".parse().unwrap();
+    let t = Terminal::try_from("$ cd /").unwrap();
     assert_eq!(t, Terminal::Command(TermCommand::Cd(ChangeDir::Root)));
 }
 
 #[test]
 fn parse_cd_in_test() {
-    let t: Terminal = "$ cd somedir".parse().unwrap();
+    let t = Terminal::try_from("$ cd somedir").unwrap();
     assert_eq!(
         t,
-        Terminal::Command(TermCommand::Cd(ChangeDir::In("somedir".to_owned())))
+        Terminal::Command(TermCommand::Cd(ChangeDir::In("somedir")))
     );
 }
 
 #[test]
 fn parse_cd_up_test() {
-    let t: Terminal = "$ cd ..".parse().unwrap();
+    let t = Terminal::try_from("$ cd ..").unwrap();
     assert_eq!(t, Terminal::Command(TermCommand::Cd(ChangeDir::Out)));
 }
 
 #[test]
 fn parse_filedata_test() {
-    let t: Terminal = "8128312 file.som".parse().unwrap();
+    let t = Terminal::try_from("8128312 file.som").unwrap();
     assert_eq!(
         t,
         Terminal::LsResult(LsResult::FileContents(FileData {
             size: 8128312,
-            filename: "file.som".to_owned()
+            filename: "file.som"
         }))
     );
 }
 
 #[test]
 fn parse_directory_data() {
-    let t: Terminal = "dir somedir".parse().unwrap();
-    assert_eq!(
-        t,
-        Terminal::LsResult(LsResult::Directory("somedir".to_owned()))
-    );
-}
\ No newline at end of file
+    let t = Terminal::try_from("dir somedir").unwrap();
+    assert_eq!(t, Terminal::LsResult(LsResult::Directory("somedir")));
+}
+
+#[test]
+fn parse_errors_test() {
+    let e = Terminal::try_from("$ rm -rf").unwrap_err();
+    assert_eq!(e.message, "unknown command `$ rm -rf`");
+
+    let e = Terminal::try_from("12x3 file").unwrap_err();
+    assert_eq!(e.column, Some(1));
+
+    assert!(Terminal::try_from("dir").is_err());
+}
+
+#[test]
+fn write_terminal_test() {
+    let input = std::fs::read_to_string("./test.txt").unwrap();
+    let lines: Vec<Terminal> = input
+        .lines()
+        .map(|l| Terminal::try_from(l).unwrap())
+        .collect();
+    assert_eq!(write_terminal(&lines), input.trim_end().to_owned() + "\n");
+
+    let lines = [
+        Terminal::Command(TermCommand::Cd(ChangeDir::Root)),
+        Terminal::Noop,
+        Terminal::Command(TermCommand::Cd(ChangeDir::In("a b"))),
+    ];
+    let written = write_terminal(&lines);
+    assert_eq!(written, "$ cd /\n\n$ cd a b\n");
+    let reparsed: Vec<Terminal> = written
+        .lines()
+        .map(|l| Terminal::try_from(l).unwrap())
+        .collect();
+    assert_eq!(reparsed, lines);
+}